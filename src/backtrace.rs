@@ -1,5 +1,8 @@
-use crate::log::Log;
+use crate::log::{Log, LogMessage};
+use crate::mark::Mark;
+use std::any::Any;
 use std::fmt::{Debug, Display};
+use std::sync::Arc;
 
 #[macro_export]
 macro_rules! raise_error {
@@ -16,24 +19,71 @@ macro_rules! raise_bug {
 }
 
 #[derive(Debug, Clone)]
-pub struct Backtrace(Vec<Log>);
+pub struct Backtrace {
+    logs: Vec<Log>,
+    /// Value carried along with the error, e.g. the value raised by `throw`.
+    payload: Option<Arc<dyn Any + Send + Sync>>,
+}
 
 impl Backtrace {
     pub fn new(log: Log) -> Backtrace {
-        Backtrace(vec![log])
+        Backtrace {
+            logs: vec![log],
+            payload: None,
+        }
+    }
+
+    pub fn with_payload(log: Log, payload: Arc<dyn Any + Send + Sync>) -> Backtrace {
+        Backtrace {
+            logs: vec![log],
+            payload: Some(payload),
+        }
     }
 
     pub fn push(&mut self, log: Log) {
-        self.0.push(log);
+        self.logs.push(log);
+    }
+
+    /// Attach a note to the error, shown after the error itself.
+    pub fn with_note(mut self, note: Log) -> Backtrace {
+        self.logs.insert(0, note);
+        self
+    }
+
+    pub fn payload(&self) -> Option<&(dyn Any + Send + Sync)> {
+        self.payload.as_deref()
+    }
+
+    /// Message of the error that starts the backtrace.
+    pub fn message(&self) -> String {
+        for log in self.logs.iter() {
+            match log.message {
+                LogMessage::ERROR(ref message) | LogMessage::BUG(ref message) => {
+                    return message.clone()
+                }
+                LogMessage::TRACE | LogMessage::NOTE(_) => continue,
+            }
+        }
+        String::new()
+    }
+
+    /// The innermost mark of the backtrace, closest to where the error is raised.
+    pub fn mark(&self) -> Option<&Mark> {
+        self.logs.iter().find_map(|log| match log.message {
+            LogMessage::NOTE(_) => None,
+            _ => log.mark.as_ref(),
+        })
     }
 }
 
 impl Display for Backtrace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for log in self.0.iter().rev() {
+        for log in self.logs.iter().rev() {
             let rendering = format!("{log}\n");
             f.write_str(&rendering)?;
         }
         Ok(())
     }
 }
+
+impl std::error::Error for Backtrace {}
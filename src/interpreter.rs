@@ -1,4 +1,8 @@
+pub mod bytecode;
+pub mod capability;
 pub mod context;
+pub mod json;
+pub mod native;
 pub mod resource;
 pub mod signal;
 pub mod standard;
@@ -0,0 +1,166 @@
+use super::context::STANDARD;
+use super::variant::boolean::Boolean;
+use super::variant::float::Float;
+use super::variant::int::Int;
+use super::variant::null::Null;
+use super::variant::strand::Strand;
+use super::variant::Variant;
+use crate::parser::atom::{Atom, AtomValue};
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::rc::Rc;
+
+/// A single step of the virtual machine in [`crate::interpreter::context::Context`].
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    /// Push a value known at compile time, including resolved standard commands.
+    CONSTANT(Variant),
+    /// Push the value bound to the identifier in the supplement or the scopes.
+    LOAD(Binding),
+    /// Replace the top of the stack with its representation.
+    REPRESENT,
+    /// Pop the given number of strands and push their concatenation.
+    CONCAT(usize),
+    /// Resolve the head of the statement through its own chunk and push it.
+    HEAD,
+    /// Pop the head of the statement and run it with the rest of the statement as body.
+    INVOKE,
+    /// Raise an error at the mark of the atom.
+    FAIL(String),
+}
+
+/// Where the variable of an identifier is found: the scope, counted from the innermost one, and
+/// the index of the entry in that scope.
+#[derive(Debug, Clone, Copy)]
+pub struct Slot {
+    pub depth: usize,
+    pub index: usize,
+}
+
+/// An identifier, resolved to the slot of its variable the first time it is run.
+///
+/// Scopes are tables that gain and lose entries at runtime, e.g. through `var`, `table-remove`
+/// or a prototype, so the slot is checked against the scopes on every run and resolved again
+/// by name when it no longer holds, see [`crate::interpreter::context::Context`].
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub identifier: String,
+    pub slot: Cell<Option<Slot>>,
+}
+
+/// Instructions compiled from an [`Atom`], cached by the context running it.
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Chunk {
+    pub fn compile(atom: &Atom) -> Rc<Chunk> {
+        let mut instructions: Vec<Instruction> = Vec::new();
+        compile_atom(atom, &mut instructions);
+        Rc::new(Chunk { instructions })
+    }
+
+    /// The binding of the chunk, if it only loads an identifier.
+    pub fn as_binding(&self) -> Option<&Binding> {
+        match self.instructions.as_slice() {
+            [Instruction::LOAD(binding)] => Some(binding),
+            _ => None,
+        }
+    }
+}
+
+/// Hasher for the chunk cache, keyed by [`Atom::id`].
+#[derive(Default)]
+pub struct AtomIdHasher(u64);
+
+impl Hasher for AtomIdHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0.rotate_left(8) ^ *byte as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        }
+    }
+
+    fn write_usize(&mut self, id: usize) {
+        // Ids are consecutive, spread them over the whole hash.
+        self.0 = (id as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    }
+}
+
+pub type BuildAtomIdHasher = BuildHasherDefault<AtomIdHasher>;
+
+fn compile_identifier(identifier: &str, instructions: &mut Vec<Instruction>) {
+    // Standard commands cannot be shadowed, so they are resolved once here.
+    match STANDARD.get(identifier) {
+        Some(variant) => instructions.push(Instruction::CONSTANT(variant.clone())),
+        None => instructions.push(Instruction::LOAD(Binding {
+            identifier: String::from(identifier),
+            slot: Cell::new(None),
+        })),
+    }
+}
+
+fn compile_atom(atom: &Atom, instructions: &mut Vec<Instruction>) {
+    match atom.value {
+        AtomValue::NULL => instructions.push(Instruction::CONSTANT(Variant::NULL(Null()))),
+        AtomValue::BOOL(boolean) => {
+            instructions.push(Instruction::CONSTANT(Variant::BOOL(Boolean::from(boolean))))
+        }
+        AtomValue::INT(int) => {
+            instructions.push(Instruction::CONSTANT(Variant::INT(Int::from(int))))
+        }
+        AtomValue::FLOAT(float) => {
+            instructions.push(Instruction::CONSTANT(Variant::FLOAT(Float::from(float))))
+        }
+        AtomValue::IDENTIFIER(ref identifier) => compile_identifier(identifier, instructions),
+        AtomValue::STRING(ref string) => {
+            let replaced = string.replace("\\n", "\n").replace("\\\\", "\\");
+            let splitted: Vec<&str> = replaced.split('`').collect();
+            if splitted.len().is_multiple_of(2) {
+                instructions.push(Instruction::FAIL(String::from(
+                    "Unterminated '`' in string.",
+                )));
+                return;
+            }
+            if splitted.len() == 1 {
+                instructions.push(Instruction::CONSTANT(Variant::STRAND(Strand::from(
+                    replaced.as_str(),
+                ))));
+                return;
+            }
+            for (i, slice) in splitted.iter().enumerate() {
+                if i % 2 == 0 {
+                    // Even index; Outside the pair of '`'.
+                    instructions.push(Instruction::CONSTANT(Variant::STRAND(Strand::from(*slice))));
+                } else if slice.is_empty() {
+                    // Odd index; Empty pair of '`'.
+                    instructions.push(Instruction::CONSTANT(Variant::STRAND(Strand::from("``"))));
+                } else {
+                    // Odd index; Between a pair of '`'.
+                    compile_identifier(slice.trim(), instructions);
+                    instructions.push(Instruction::REPRESENT);
+                }
+            }
+            instructions.push(Instruction::CONCAT(splitted.len()));
+        }
+        AtomValue::STATEMENT(ref statement) => {
+            let head = match statement.first() {
+                Some(head) => head,
+                None => {
+                    instructions.push(Instruction::CONSTANT(Variant::NULL(Null())));
+                    return;
+                }
+            };
+            match head.value {
+                AtomValue::STATEMENT(_) => instructions.push(Instruction::HEAD),
+                _ => compile_atom(head, instructions),
+            }
+            instructions.push(Instruction::INVOKE);
+        }
+    }
+}
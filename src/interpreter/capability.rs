@@ -0,0 +1,81 @@
+use super::resource::ResourcePath;
+use crate::backtrace::Backtrace;
+use crate::mark::Mark;
+use crate::raise_error;
+
+/// What scripts may reach outside of the interpreter, checked by the commands that reach it.
+///
+/// Everything is allowed by default. [`Capabilities::sandbox`] allows nothing, for running
+/// untrusted scripts, and the host grants capabilities back one by one. Commands that access
+/// files or the network must check [`Capabilities::check_file`] or
+/// [`Capabilities::check_network`] before doing so.
+#[derive(Debug, Clone)]
+pub struct Capabilities {
+    /// Programs `console` may run, any program if `None`.
+    pub console: Option<Vec<String>>,
+    /// Roots `import` may load scripts from, by their resolved path, any root if `None`.
+    pub import_roots: Option<Vec<ResourcePath>>,
+    /// Whether commands may access files.
+    pub file: bool,
+    /// Whether commands may access the network.
+    pub network: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Capabilities {
+            console: None,
+            import_roots: None,
+            file: true,
+            network: true,
+        }
+    }
+}
+
+impl Capabilities {
+    /// Capabilities that allow nothing.
+    pub fn sandbox() -> Self {
+        Capabilities {
+            console: Some(Vec::new()),
+            import_roots: Some(Vec::new()),
+            file: false,
+            network: false,
+        }
+    }
+
+    pub fn check_console(&self, program: &str, mark: Option<Mark>) -> Result<(), Backtrace> {
+        match self.console {
+            Some(ref programs) if !programs.iter().any(|allowed| allowed == program) => {
+                raise_error!(mark, "Running '{}' with `console` is not allowed.", program);
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check_import(&self, path: &ResourcePath, mark: Option<Mark>) -> Result<(), Backtrace> {
+        match self.import_roots {
+            Some(ref roots) if !roots.iter().any(|root| path.starts_with(root)) => {
+                raise_error!(
+                    mark,
+                    "Importing '{}' is not allowed, it is outside of the allowed roots.",
+                    Into::<String>::into(path.clone())
+                );
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check_file(&self, mark: Option<Mark>) -> Result<(), Backtrace> {
+        if !self.file {
+            raise_error!(mark, "Accessing files is not allowed.");
+        }
+        Ok(())
+    }
+
+    pub fn check_network(&self, mark: Option<Mark>) -> Result<(), Backtrace> {
+        if !self.network {
+            raise_error!(mark, "Accessing the network is not allowed.");
+        }
+        Ok(())
+    }
+}
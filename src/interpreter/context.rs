@@ -1,41 +1,118 @@
+use super::capability::Capabilities;
+use super::native::NativeFn;
 use super::resource::system_resource::SystemResource;
 use super::resource::Resource;
 use super::resource::ResourcePath;
+use super::standard::abs_fn::abs_fn;
+use super::standard::acos_fn::acos_fn;
 use super::standard::add_fn::add_fn;
+use super::standard::and_fn::and_fn;
+use super::standard::asin_fn::asin_fn;
 use super::standard::assert_fn::assert_fn;
+use super::standard::atan_fn::atan_fn;
 use super::standard::break_fn::break_fn;
+use super::standard::catch_fn::catch_fn;
+use super::standard::ceil_fn::ceil_fn;
 use super::standard::closure_fn::closure_fn;
 use super::standard::console_fn::console_fn;
 use super::standard::continue_fn::continue_fn;
+use super::standard::cos_fn::cos_fn;
 use super::standard::div_fn::div_fn;
 use super::standard::duplicate_fn::duplicate_fn;
+use super::standard::elif_fn::elif_fn;
+use super::standard::else_fn::else_fn;
 use super::standard::eq_fn::eq_fn;
+use super::standard::export_fn::export_fn;
+use super::standard::floor_fn::floor_fn;
+use super::standard::for_fn::for_fn;
 use super::standard::g_fn::g_fn;
 use super::standard::ge_fn::ge_fn;
+use super::standard::if_fn::if_fn;
 use super::standard::import_fn::import_fn;
+use super::standard::json_parse_fn::json_parse_fn;
+use super::standard::json_stringify_fn::json_stringify_fn;
 use super::standard::l_fn::l_fn;
 use super::standard::le_fn::le_fn;
+use super::standard::list_all_fn::list_all_fn;
+use super::standard::list_any_fn::list_any_fn;
+use super::standard::list_concat_fn::list_concat_fn;
+use super::standard::list_contains_fn::list_contains_fn;
+use super::standard::list_filter_fn::list_filter_fn;
+use super::standard::list_find_fn::list_find_fn;
 use super::standard::list_fn::list_fn;
 use super::standard::list_get_fn::list_get_fn;
+use super::standard::list_index_of_fn::list_index_of_fn;
+use super::standard::list_insert_fn::list_insert_fn;
 use super::standard::list_length_fn::list_length_fn;
+use super::standard::list_map_fn::list_map_fn;
 use super::standard::list_pop_fn::list_pop_fn;
 use super::standard::list_push_fn::list_push_fn;
+use super::standard::list_reduce_fn::list_reduce_fn;
+use super::standard::list_remove_fn::list_remove_fn;
+use super::standard::list_reverse_fn::list_reverse_fn;
+use super::standard::list_set_fn::list_set_fn;
+use super::standard::list_slice_fn::list_slice_fn;
+use super::standard::list_sort_fn::list_sort_fn;
+use super::standard::list_zip_fn::list_zip_fn;
+use super::standard::log_fn::log_fn;
+use super::standard::max_fn::max_fn;
+use super::standard::min_fn::min_fn;
+use super::standard::mod_fn::mod_fn;
 use super::standard::mul_fn::mul_fn;
+use super::standard::not_fn::not_fn;
+use super::standard::or_fn::or_fn;
 use super::standard::parameter_fn::parameter_fn;
+use super::standard::pow_fn::pow_fn;
 use super::standard::print_fn::print_fn;
 use super::standard::println_fn::println_fn;
 use super::standard::return_fn::return_fn;
+use super::standard::round_fn::round_fn;
 use super::standard::set_fn::set_fn;
+use super::standard::sin_fn::sin_fn;
+use super::standard::sqrt_fn::sqrt_fn;
+use super::standard::string_chars_fn::string_chars_fn;
+use super::standard::string_ends_with_fn::string_ends_with_fn;
+use super::standard::string_find_fn::string_find_fn;
+use super::standard::string_join_fn::string_join_fn;
+use super::standard::string_length_fn::string_length_fn;
+use super::standard::string_lower_fn::string_lower_fn;
+use super::standard::string_replace_fn::string_replace_fn;
+use super::standard::string_slice_fn::string_slice_fn;
+use super::standard::string_split_fn::string_split_fn;
+use super::standard::string_starts_with_fn::string_starts_with_fn;
+use super::standard::string_trim_fn::string_trim_fn;
+use super::standard::string_upper_fn::string_upper_fn;
 use super::standard::sub_fn::sub_fn;
+use super::standard::table_extend_fn::table_extend_fn;
 use super::standard::table_fn::table_fn;
+use super::standard::table_get_fn::table_get_fn;
+use super::standard::table_has_fn::table_has_fn;
+use super::standard::table_keys_fn::table_keys_fn;
+use super::standard::table_length_fn::table_length_fn;
+use super::standard::table_merge_fn::table_merge_fn;
+use super::standard::table_prototype_fn::table_prototype_fn;
+use super::standard::table_remove_fn::table_remove_fn;
+use super::standard::table_set_fn::table_set_fn;
+use super::standard::table_set_prototype_fn::table_set_prototype_fn;
+use super::standard::table_values_fn::table_values_fn;
+use super::standard::tan_fn::tan_fn;
+use super::standard::throw_fn::throw_fn;
+use super::standard::try_fn::try_fn;
 use super::standard::var_fn::var_fn;
 use super::standard::when_fn::when_fn;
 use super::standard::while_fn::while_fn;
 
+use super::bytecode::Binding;
+use super::bytecode::BuildAtomIdHasher;
+use super::bytecode::Chunk;
+use super::bytecode::Instruction;
+use super::bytecode::Slot;
 use super::signal::Signal;
 use super::variant::boolean::Boolean;
+use super::variant::closure::Closure;
 use super::variant::command::Command;
 use super::variant::float::Float;
+use super::variant::int::Int;
 use super::variant::list::List;
 use super::variant::null::Null;
 use super::variant::represent::Represent;
@@ -44,15 +121,18 @@ use super::variant::table::Table;
 use super::variant::Variant;
 use crate::backtrace::Backtrace;
 use crate::log::Log;
+use crate::mark::Mark;
 use crate::parser::atom::generate_statements;
 use crate::parser::atom::Atom;
 use crate::parser::atom::AtomValue;
 use crate::parser::token::tokenize;
-use crate::raise_error;
+use crate::{atom_as_identifier, atom_as_statement, raise_bug, raise_error};
 use std::collections::HashMap;
+use std::mem;
+use std::rc::Rc;
 
 lazy_static::lazy_static! {
-    static ref STANDARD: HashMap<&'static str, Variant> = {
+    pub(super) static ref STANDARD: HashMap<&'static str, Variant> = {
         let standard: HashMap<&'static str, Variant> = HashMap::from([
             ("var", Variant::COMMAND(Command::new(var_fn))),
             ("set", Variant::COMMAND(Command::new(set_fn))),
@@ -63,7 +143,11 @@ lazy_static::lazy_static! {
             ("print", Variant::COMMAND(Command::new(print_fn))),
             ("println", Variant::COMMAND(Command::new(println_fn))),
             ("when", Variant::COMMAND(Command::new(when_fn))),
+            ("if", Variant::COMMAND(Command::new(if_fn))),
+            ("elif", Variant::COMMAND(Command::new(elif_fn))),
+            ("else", Variant::COMMAND(Command::new(else_fn))),
             ("while", Variant::COMMAND(Command::new(while_fn))),
+            ("for", Variant::COMMAND(Command::new(for_fn))),
             ("list", Variant::COMMAND(Command::new(list_fn))),
             ("list-get", Variant::COMMAND(Command::new(list_get_fn))),
             ("list-push", Variant::COMMAND(Command::new(list_push_fn))),
@@ -84,14 +168,90 @@ lazy_static::lazy_static! {
             ("g", Variant::COMMAND(Command::new(g_fn))),
             ("le", Variant::COMMAND(Command::new(le_fn))),
             ("l", Variant::COMMAND(Command::new(l_fn))),
+            ("and", Variant::COMMAND(Command::new(and_fn))),
+            ("or", Variant::COMMAND(Command::new(or_fn))),
+            ("not", Variant::COMMAND(Command::new(not_fn))),
             ("console", Variant::COMMAND(Command::new(console_fn))),
             ("duplicate", Variant::COMMAND(Command::new(duplicate_fn))),
             ("assert", Variant::COMMAND(Command::new(assert_fn))),
+            ("throw", Variant::COMMAND(Command::new(throw_fn))),
+            ("try", Variant::COMMAND(Command::new(try_fn))),
+            ("catch", Variant::COMMAND(Command::new(catch_fn))),
+            ("json-parse", Variant::COMMAND(Command::new(json_parse_fn))),
+            (
+                "json-stringify",
+                Variant::COMMAND(Command::new(json_stringify_fn)),
+            ),
+            ("string-length", Variant::COMMAND(Command::new(string_length_fn))),
+            ("string-slice", Variant::COMMAND(Command::new(string_slice_fn))),
+            ("string-split", Variant::COMMAND(Command::new(string_split_fn))),
+            ("string-join", Variant::COMMAND(Command::new(string_join_fn))),
+            ("string-find", Variant::COMMAND(Command::new(string_find_fn))),
+            ("string-replace", Variant::COMMAND(Command::new(string_replace_fn))),
+            ("string-trim", Variant::COMMAND(Command::new(string_trim_fn))),
+            ("string-upper", Variant::COMMAND(Command::new(string_upper_fn))),
+            ("string-lower", Variant::COMMAND(Command::new(string_lower_fn))),
+            ("string-starts-with", Variant::COMMAND(Command::new(string_starts_with_fn))),
+            ("string-ends-with", Variant::COMMAND(Command::new(string_ends_with_fn))),
+            ("string-chars", Variant::COMMAND(Command::new(string_chars_fn))),
+            ("mod", Variant::COMMAND(Command::new(mod_fn))),
+            ("pow", Variant::COMMAND(Command::new(pow_fn))),
+            ("floor", Variant::COMMAND(Command::new(floor_fn))),
+            ("ceil", Variant::COMMAND(Command::new(ceil_fn))),
+            ("round", Variant::COMMAND(Command::new(round_fn))),
+            ("abs", Variant::COMMAND(Command::new(abs_fn))),
+            ("min", Variant::COMMAND(Command::new(min_fn))),
+            ("max", Variant::COMMAND(Command::new(max_fn))),
+            ("sqrt", Variant::COMMAND(Command::new(sqrt_fn))),
+            ("log", Variant::COMMAND(Command::new(log_fn))),
+            ("sin", Variant::COMMAND(Command::new(sin_fn))),
+            ("cos", Variant::COMMAND(Command::new(cos_fn))),
+            ("tan", Variant::COMMAND(Command::new(tan_fn))),
+            ("asin", Variant::COMMAND(Command::new(asin_fn))),
+            ("acos", Variant::COMMAND(Command::new(acos_fn))),
+            ("atan", Variant::COMMAND(Command::new(atan_fn))),
+            ("list-set", Variant::COMMAND(Command::new(list_set_fn))),
+            ("list-insert", Variant::COMMAND(Command::new(list_insert_fn))),
+            ("list-remove", Variant::COMMAND(Command::new(list_remove_fn))),
+            ("list-slice", Variant::COMMAND(Command::new(list_slice_fn))),
+            ("list-concat", Variant::COMMAND(Command::new(list_concat_fn))),
+            ("list-reverse", Variant::COMMAND(Command::new(list_reverse_fn))),
+            ("list-contains", Variant::COMMAND(Command::new(list_contains_fn))),
+            ("list-index-of", Variant::COMMAND(Command::new(list_index_of_fn))),
+            ("list-sort", Variant::COMMAND(Command::new(list_sort_fn))),
+            ("list-map", Variant::COMMAND(Command::new(list_map_fn))),
+            ("list-filter", Variant::COMMAND(Command::new(list_filter_fn))),
+            ("list-reduce", Variant::COMMAND(Command::new(list_reduce_fn))),
+            ("list-any", Variant::COMMAND(Command::new(list_any_fn))),
+            ("list-all", Variant::COMMAND(Command::new(list_all_fn))),
+            ("list-find", Variant::COMMAND(Command::new(list_find_fn))),
+            ("list-zip", Variant::COMMAND(Command::new(list_zip_fn))),
+            ("table-keys", Variant::COMMAND(Command::new(table_keys_fn))),
+            ("table-values", Variant::COMMAND(Command::new(table_values_fn))),
+            ("table-has", Variant::COMMAND(Command::new(table_has_fn))),
+            ("table-get", Variant::COMMAND(Command::new(table_get_fn))),
+            ("table-set", Variant::COMMAND(Command::new(table_set_fn))),
+            ("table-remove", Variant::COMMAND(Command::new(table_remove_fn))),
+            ("table-length", Variant::COMMAND(Command::new(table_length_fn))),
+            ("table-merge", Variant::COMMAND(Command::new(table_merge_fn))),
+            ("table-extend", Variant::COMMAND(Command::new(table_extend_fn))),
+            ("table-prototype", Variant::COMMAND(Command::new(table_prototype_fn))),
+            ("table-set-prototype", Variant::COMMAND(Command::new(table_set_prototype_fn))),
+            ("export", Variant::COMMAND(Command::new(export_fn))),
         ]);
         standard
     };
 }
 
+/// A module that is being run, see [`Context::run_resource`].
+struct ModuleFrame {
+    path: ResourcePath,
+    /// Where the module is imported, or `None` for the script run first.
+    mark: Option<Mark>,
+    /// Names given to `export`, with where they are exported.
+    exports: Option<Vec<(String, Mark)>>,
+}
+
 /// The runtime that runs Blinklet code.
 pub struct Context {
     pub(super) scopes: Vec<Table>,
@@ -99,8 +259,22 @@ pub struct Context {
     pub supplement: HashMap<&'static str, Variant>,
     /// Variants passed into script as parameters. The script can retrieve it with `parameter` command.
     pub slots: Vec<Variant>,
+    /// Keyword arguments passed into closure, retrieved by name with `parameter` command.
+    pub keywords: Vec<(String, Variant)>,
+    /// Where the running closure is called, for errors on its arguments.
+    pub call_mark: Option<Mark>,
+    /// Modules that are being run, the innermost last.
+    modules: Vec<ModuleFrame>,
+    /// Values of modules that are run, by their resolved path.
+    module_cache: HashMap<ResourcePath, Variant>,
     /// Resource to retrieve scripts from, defaulted to [`crate::interpreter::resource::SystemResource`].
     pub resource: Box<dyn Resource>,
+    /// What the scripts may reach outside of the interpreter.
+    pub capabilities: Capabilities,
+    /// Tables whose `__repr` is running, representing them again inside it shows their entries.
+    pub(crate) representing: Vec<Table>,
+    /// Compiled chunks of the atoms that are run, by [`Atom::id`].
+    chunks: HashMap<usize, Rc<Chunk>, BuildAtomIdHasher>,
 }
 
 impl Context {
@@ -109,7 +283,14 @@ impl Context {
             scopes: Vec::new(),
             supplement: HashMap::new(),
             slots: Vec::new(),
+            keywords: Vec::new(),
+            call_mark: None,
+            modules: Vec::new(),
+            module_cache: HashMap::new(),
             resource: Box::new(SystemResource::default()),
+            capabilities: Capabilities::default(),
+            representing: Vec::new(),
+            chunks: HashMap::default(),
         };
 
         let make_list_iter_fn_code = include_str!("./standard/make_list_iter_fn.k");
@@ -119,88 +300,200 @@ impl Context {
     }
 
     pub fn resolve_variant(&mut self, atom: &Atom) -> Result<Variant, Backtrace> {
-        match atom.value {
-            AtomValue::STATEMENT(ref statement) => {
-                let signal = self.run_statement(statement.as_slice())?;
-                match signal {
-                    Signal::RETURN(value, _) | Signal::COMPLETE(value) => Ok(value),
-                    _ => {
-                        raise_error!(Some(atom.mark.clone()), "Unexpected control command.");
+        let signal = self.execute(atom)?;
+        match signal {
+            Signal::RETURN(value, _) | Signal::COMPLETE(value) => Ok(value),
+            _ => {
+                raise_error!(Some(atom.mark.clone()), "Unexpected control command.");
+            }
+        }
+    }
+
+    /// The compiled chunk of the atom, compiled the first time the atom is run.
+    fn chunk(&mut self, atom: &Atom) -> Rc<Chunk> {
+        self.chunks
+            .entry(atom.id())
+            .or_insert_with(|| Chunk::compile(atom))
+            .clone()
+    }
+
+    /// Run the compiled chunk of the atom.
+    fn execute(&mut self, atom: &Atom) -> Result<Signal, Backtrace> {
+        let chunk = self.chunk(atom);
+
+        // Most atoms are a single value, skip the stack for them.
+        match chunk.instructions.as_slice() {
+            [Instruction::CONSTANT(value)] => return Ok(Signal::COMPLETE(value.clone())),
+            [Instruction::LOAD(binding)] => return Ok(Signal::COMPLETE(self.load(binding, atom)?)),
+            [Instruction::CONSTANT(head), Instruction::INVOKE] => {
+                let statement = atom_as_statement!(atom);
+                return self.invoke(head.clone(), statement.as_slice());
+            }
+            _ => {}
+        }
+
+        let mut stack: Vec<Variant> = Vec::new();
+        for instruction in chunk.instructions.iter() {
+            match instruction {
+                Instruction::CONSTANT(value) => stack.push(value.clone()),
+                Instruction::LOAD(binding) => stack.push(self.load(binding, atom)?),
+                Instruction::REPRESENT => {
+                    let value = stack.pop();
+                    if value.is_none() {
+                        raise_bug!(Some(atom.mark.clone()), "Virtual machine stack underflow.");
                     }
+                    let representation =
+                        value.unwrap().represent_in(Some(atom.mark.clone()), self)?;
+                    stack.push(Variant::STRAND(Strand::from(representation)));
                 }
-            }
-            AtomValue::BOOL(boolean) => Ok(Variant::BOOL(Boolean::from(boolean))),
-            AtomValue::NULL => Ok(Variant::NULL(Null())),
-            AtomValue::STRING(ref string) => {
-                let mut result = String::new();
-                let replaced = string.replace("\\n", "\n").replace("\\\\", "\\");
-                let splitted: Vec<&str> = replaced.split('`').collect();
-                if splitted.len() % 2 == 0 {
-                    raise_error!(Some(atom.mark.clone()), "Unterminated '`' in string.");
+                Instruction::CONCAT(count) => {
+                    if stack.len() < *count {
+                        raise_bug!(Some(atom.mark.clone()), "Virtual machine stack underflow.");
+                    }
+                    let mut result = String::new();
+                    for value in stack.split_off(stack.len() - count) {
+                        let representation = value.represent_in(Some(atom.mark.clone()), self)?;
+                        result.push_str(representation.as_str());
+                    }
+                    stack.push(Variant::STRAND(Strand::from(result)));
                 }
-                for (i, slice) in splitted.iter().enumerate() {
-                    if i % 2 == 0 {
-                        // Even index; Outside the pair of '`'.
-                        result.push_str(slice);
-                    } else {
-                        // Odd index; Between a pair of '`'.
-                        if slice.len() == 0 {
-                            result.push_str("``");
-                        } else {
-                            let variant = self.resolve_variant(&Atom::new_identifier(
-                                slice.trim().to_string(),
-                                atom.mark.clone(),
-                            ))?;
-                            result.push_str(variant.represent(Some(atom.mark.clone()))?.as_str());
-                        }
+                Instruction::HEAD => {
+                    let statement = atom_as_statement!(atom);
+                    stack.push(self.resolve_variant(&statement[0])?);
+                }
+                Instruction::INVOKE => {
+                    let statement = atom_as_statement!(atom);
+                    let head = stack.pop();
+                    if head.is_none() {
+                        raise_bug!(Some(atom.mark.clone()), "Virtual machine stack underflow.");
                     }
+                    return self.invoke(head.unwrap(), statement.as_slice());
                 }
-                Ok(Variant::STRAND(Strand::from(result)))
-            }
-            AtomValue::FLOAT(float) => Ok(Variant::FLOAT(Float::from(float))),
-            AtomValue::IDENTIFIER(ref identifier) => {
-                // Query standard.
-                let value = STANDARD.get(identifier.as_str());
-                if value.is_some() {
-                    return Ok(value.unwrap().clone());
+                Instruction::FAIL(message) => {
+                    raise_error!(Some(atom.mark.clone()), "{}", message);
                 }
+            }
+        }
 
-                // Query suppliment.
-                let value = self.supplement.get(identifier.as_str());
-                if value.is_some() {
-                    return Ok(value.unwrap().clone());
-                }
+        match stack.pop() {
+            Some(value) => Ok(Signal::COMPLETE(value)),
+            None => {
+                raise_bug!(Some(atom.mark.clone()), "Virtual machine stack underflow.");
+            }
+        }
+    }
 
-                // Query table.
-                let scopes_count = self.scopes.len();
-                if scopes_count == 0 {
-                    raise_error!(
-                        Some(atom.mark.clone()),
-                        "Identifier '{}' is not defined.",
-                        identifier
-                    );
-                }
+    /// Look up an identifier that is not a standard command.
+    fn load(&self, binding: &Binding, atom: &Atom) -> Result<Variant, Backtrace> {
+        let identifier = &binding.identifier;
 
-                for i in (0..scopes_count).rev() {
-                    let table = self.scopes.get(i);
-                    if table.is_none() {
-                        continue;
-                    }
-                    let table = table.unwrap();
-                    let value = table.get(identifier, Some(atom.mark.clone()))?;
-                    if value.is_none() {
-                        continue;
-                    }
-                    return Ok(value.unwrap());
+        // Query suppliment.
+        let value = self.supplement.get(identifier.as_str());
+        if value.is_some() {
+            return Ok(value.unwrap().clone());
+        }
+
+        // Query the slot the variable was last found at.
+        if let Some((position, index)) = self.cached_slot(binding) {
+            if let Ok(Some(value)) = self.scopes[position].get_at(index, identifier, None) {
+                return Ok(value);
+            }
+        }
+
+        // Query table, falling through its prototype chain. Building the mark is costly on
+        // this path, so it is only built to retry a lookup that fails.
+        for (depth, table) in self.scopes.iter().rev().enumerate() {
+            let value = match table.lookup(identifier, None) {
+                Ok(value) => value,
+                Err(_) => table.lookup(identifier, Some(atom.mark.clone()))?,
+            };
+            if value.is_none() {
+                continue;
+            }
+            // Variables from a prototype chain are looked up by name every time.
+            if let Ok(Some(index)) = table.index_of(identifier, None) {
+                binding.slot.set(Some(Slot { depth, index }));
+            }
+            return Ok(value.unwrap());
+        }
+
+        raise_error!(
+            Some(atom.mark.clone()),
+            "Identifier '{}' is not defined.",
+            identifier
+        );
+    }
+
+    /// Get the value of the identifier from the supplement or the scopes, without running
+    /// anything, or `None` if it is not declared.
+    pub fn lookup(&self, identifier: &String) -> Result<Option<Variant>, Backtrace> {
+        if let Some(value) = self.supplement.get(identifier.as_str()) {
+            return Ok(Some(value.clone()));
+        }
+        for table in self.scopes.iter().rev() {
+            if let Some(value) = table.lookup(identifier, None)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Position in the scopes and index of the entry of the slot the binding was last found at,
+    /// if no scope inside of it may have the identifier.
+    ///
+    /// The entry may still be of another identifier, which the caller has to check.
+    fn cached_slot(&self, binding: &Binding) -> Option<(usize, usize)> {
+        let slot = binding.slot.get()?;
+        let position = self.scopes.len().checked_sub(slot.depth + 1)?;
+        for table in self.scopes[position + 1..].iter() {
+            if table
+                .may_contain_deep(&binding.identifier, None)
+                .unwrap_or(true)
+            {
+                return None;
+            }
+        }
+        Some((position, slot.index))
+    }
+
+    /// Change the variable of the identifier atom in the innermost scope that has it. A variable
+    /// from a prototype chain is shadowed by a new entry in the scope rather than changed.
+    pub fn assign(&mut self, atom: &Atom, mut value: Variant, mark: Mark) -> Result<(), Backtrace> {
+        let identifier = atom_as_identifier!(atom);
+        let chunk = self.chunk(atom);
+        let binding = chunk.as_binding();
+
+        if let Some((position, index)) = binding.and_then(|binding| self.cached_slot(binding)) {
+            match self.scopes[position].set_at(index, identifier, value, None) {
+                Ok(None) => return Ok(()),
+                Ok(Some(unset)) => value = unset,
+                Err(_) => {
+                    raise_bug!(Some(mark), "Thread is poisoned while locking mutex.");
                 }
+            }
+        }
 
-                raise_error!(
-                    Some(atom.mark.clone()),
-                    "Identifier '{}' is not defined.",
-                    identifier
-                );
+        let scopes_count = self.scopes.len();
+        if scopes_count == 0 {
+            raise_bug!(Some(mark), "Empty scopes should be unreachable.");
+        }
+        for i in (0..scopes_count).rev() {
+            let table = self.scopes.get_mut(i).unwrap();
+            if table.contains_key_deep(identifier, Some(mark.clone()))? {
+                table
+                    .insert(identifier.clone(), value, Some(mark.clone()))
+                    .unwrap();
+                if let (Some(binding), Ok(Some(index))) =
+                    (binding, table.index_of(identifier, None))
+                {
+                    let depth = scopes_count - 1 - i;
+                    binding.slot.set(Some(Slot { depth, index }));
+                }
+                return Ok(());
             }
         }
+
+        raise_error!(Some(mark), "'{}' is not declared.", identifier);
     }
 
     pub fn resolve_boolean(&mut self, atom: &Atom) -> Result<Boolean, Backtrace> {
@@ -221,6 +514,50 @@ impl Context {
         }
     }
 
+    /// Resolve an integer or a float as a float.
+    pub fn resolve_number(&mut self, atom: &Atom) -> Result<f64, Backtrace> {
+        let value = self.resolve_variant(atom)?;
+        Context::as_number(&value, atom)
+    }
+
+    /// Convert the value already resolved from the atom into a number.
+    pub fn as_number(value: &Variant, atom: &Atom) -> Result<f64, Backtrace> {
+        match value {
+            Variant::INT(int) => Ok(Into::<i64>::into(*int) as f64),
+            Variant::FLOAT(float) => Ok((*float).into()),
+            _ => {
+                raise_error!(Some(atom.mark.clone()), "Variant given is not a number.");
+            }
+        }
+    }
+
+    pub fn resolve_strand(&mut self, atom: &Atom) -> Result<Strand, Backtrace> {
+        let value = self.resolve_variant(atom)?;
+        if let Variant::STRAND(strand) = value {
+            Ok(strand)
+        } else {
+            raise_error!(Some(atom.mark.clone()), "Variant given is not a string.");
+        }
+    }
+
+    pub fn resolve_closure(&mut self, atom: &Atom) -> Result<Closure, Backtrace> {
+        let value = self.resolve_variant(atom)?;
+        if let Variant::CLOSURE(closure) = value {
+            Ok(closure)
+        } else {
+            raise_error!(Some(atom.mark.clone()), "Variant given is not a closure.");
+        }
+    }
+
+    pub fn resolve_table(&mut self, atom: &Atom) -> Result<Table, Backtrace> {
+        let value = self.resolve_variant(atom)?;
+        if let Variant::TABLE(table) = value {
+            Ok(table)
+        } else {
+            raise_error!(Some(atom.mark.clone()), "Variant given is not a table.");
+        }
+    }
+
     pub fn resolve_float(&mut self, atom: &Atom) -> Result<Float, Backtrace> {
         let value = self.resolve_variant(atom)?;
         if let Variant::FLOAT(float) = value {
@@ -230,17 +567,32 @@ impl Context {
         }
     }
 
+    pub fn resolve_int(&mut self, atom: &Atom) -> Result<Int, Backtrace> {
+        let value = self.resolve_variant(atom)?;
+        if let Variant::INT(int) = value {
+            Ok(int)
+        } else {
+            raise_error!(Some(atom.mark.clone()), "Variant given is not an integer.");
+        }
+    }
+
     pub fn run_statement(&mut self, statement: &[Atom]) -> Result<Signal, Backtrace> {
         if statement.is_empty() {
             return Ok(Signal::COMPLETE(Variant::NULL(Null())));
         }
+        let head = statement.first().unwrap();
+        let value = self.resolve_variant(head)?;
+        self.invoke(value, statement)
+    }
+
+    /// Run the statement with its head resolved into `value`.
+    fn invoke(&mut self, value: Variant, statement: &[Atom]) -> Result<Signal, Backtrace> {
         if self.scopes.len() == 0 {
             self.scopes.push(Table::default())
         }
         let head = statement.first().unwrap();
         let body = &statement[1..];
 
-        let value = self.resolve_variant(head)?;
         match value {
             Variant::COMMAND(command) => {
                 let result = command.call(self, head, body);
@@ -254,7 +606,7 @@ impl Context {
             }
 
             Variant::CLOSURE(mut closure) => {
-                let result = closure.call_mut(self, body);
+                let result = closure.call_mut(self, head, body);
                 if result.is_ok() {
                     return result;
                 }
@@ -265,7 +617,7 @@ impl Context {
             }
 
             Variant::TABLE(table) => {
-                let result = self.run_statements(&statement[1..], table);
+                let result = self.run_statements(body, table);
                 if result.is_ok() {
                     return result;
                 }
@@ -295,8 +647,8 @@ impl Context {
 
         self.scopes.push(table);
         for atom in statements.iter() {
-            if let AtomValue::STATEMENT(ref statement) = atom.value {
-                let result = self.run_statement(&statement.as_slice());
+            if let AtomValue::STATEMENT(_) = atom.value {
+                let result = self.execute(atom);
                 if result.is_err() {
                     self.scopes.pop();
                     return result;
@@ -319,16 +671,135 @@ impl Context {
         Ok(Signal::COMPLETE(Variant::TABLE(table)))
     }
 
-    pub fn run_resource(&mut self, mut path: ResourcePath) -> Result<Signal, Backtrace> {
-        let module_name: String = path.clone().into();
+    /// Run the statements in `table` as a new scope, completing with the value of the last
+    /// statement, or `null` if there is none.
+    pub fn run_block(&mut self, statements: &[Atom], table: Table) -> Result<Signal, Backtrace> {
+        let mut value = Variant::NULL(Null::new());
+        self.scopes.push(table);
+        for atom in statements.iter() {
+            let result = if let AtomValue::STATEMENT(_) = atom.value {
+                self.execute(atom)
+            } else {
+                Err(Backtrace::new(Log::error(
+                    String::from("Expecting statement."),
+                    Some(atom.mark.clone()),
+                )))
+            };
+
+            match result {
+                Ok(Signal::COMPLETE(last)) => value = last,
+                _ => {
+                    self.scopes.pop();
+                    return result;
+                }
+            }
+        }
+        self.scopes.pop();
+        Ok(Signal::COMPLETE(value))
+    }
+
+    /// Run the script at `path` as a module, the script can retrieve the slots with `parameter`.
+    pub fn run_resource(&mut self, path: ResourcePath) -> Result<Signal, Backtrace> {
+        Ok(Signal::COMPLETE(self.load_module(path, None)?))
+    }
+
+    /// Import the module at `path`, running it only the first time it is imported.
+    ///
+    /// The module runs in its own global scope without arguments, so its value does not depend
+    /// on the importer.
+    pub fn import_resource(
+        &mut self,
+        path: ResourcePath,
+        mark: Mark,
+    ) -> Result<Variant, Backtrace> {
+        let scopes = mem::take(&mut self.scopes);
+        let slots = mem::take(&mut self.slots);
+        let keywords = mem::take(&mut self.keywords);
+        let result = self.load_module(path, Some(mark));
+        self.keywords = keywords;
+        self.slots = slots;
+        self.scopes = scopes;
+        result
+    }
+
+    fn load_module(
+        &mut self,
+        path: ResourcePath,
+        mark: Option<Mark>,
+    ) -> Result<Variant, Backtrace> {
+        let resolved = self.resource.resolve(path.clone())?;
+        self.capabilities.check_import(&resolved, mark.clone())?;
+        if let Some(value) = self.module_cache.get(&resolved) {
+            return Ok(value.clone());
+        }
+        if let Some(frame) = self.modules.iter().find(|frame| frame.path == resolved) {
+            let module_name: String = resolved.clone().into();
+            let mut backtrace = Backtrace::new(Log::error(
+                format!("Circular import of '{}'.", module_name),
+                mark,
+            ));
+            if let Some(first_mark) = frame.mark.clone() {
+                backtrace = backtrace.with_note(Log::note(
+                    format!("'{}' is being imported here.", module_name),
+                    Some(first_mark),
+                ));
+            }
+            return Err(backtrace);
+        }
+
+        // Imports in the module are relative to where the module is found.
+        let module_name: String = path.into();
         let previous_prefix = self.resource.get_prefix().clone();
-        let mut new_prefix = self.resource.get_prefix().clone();
-        let _ = new_prefix.append(&mut path.remove_parent_path());
-        self.resource.set_prefix(new_prefix);
-        let code = self.resource.get_code(path)?;
-        let result = self.run_code(module_name, code);
+        self.resource
+            .set_prefix(resolved.clone().remove_parent_path());
+        self.modules.push(ModuleFrame {
+            path: resolved.clone(),
+            mark,
+            exports: None,
+        });
+        let result = match self.resource.get_code(resolved.clone()) {
+            Ok(code) => self.run_code(module_name, code),
+            Err(backtrace) => Err(backtrace),
+        };
+        let frame = self.modules.pop().unwrap();
         self.resource.set_prefix(previous_prefix);
-        result
+
+        let value = match result? {
+            Signal::COMPLETE(Variant::TABLE(table)) if frame.exports.is_some() => {
+                let mut exported = Table::default();
+                for (name, mark) in frame.exports.unwrap() {
+                    match table.get(&name, Some(mark.clone()))? {
+                        Some(value) => exported.insert(name, value, Some(mark))?,
+                        None => {
+                            raise_error!(Some(mark), "'{}' is exported but not declared.", name);
+                        }
+                    };
+                }
+                Variant::TABLE(exported)
+            }
+            Signal::COMPLETE(value) | Signal::RETURN(value, _) => value,
+            Signal::BREAK(mark) | Signal::CONTINUE(mark) => {
+                raise_error!(Some(mark), "Unexpected control command in module.");
+            }
+        };
+        self.module_cache.insert(resolved, value.clone());
+        Ok(value)
+    }
+
+    /// Add names to the public surface of the module that is being run.
+    pub fn export(&mut self, name: String, mark: Mark) -> Result<(), Backtrace> {
+        let frame = match self.modules.last_mut() {
+            Some(frame) => frame,
+            None => {
+                raise_error!(Some(mark), "Nothing is exported outside of a module.");
+            }
+        };
+        let exports = frame.exports.get_or_insert_with(Vec::new);
+        if exports.iter().any(|(exported, _)| exported == &name) {
+            raise_error!(Some(mark), "'{}' has already been exported.", name);
+        }
+        exports.push((name, mark));
+        Ok(())
     }
 
     pub fn run_code(&mut self, name: String, code: String) -> Result<Signal, Backtrace> {
@@ -337,6 +808,20 @@ impl Context {
         self.run_statements(result.as_slice(), Table::default())
     }
 
+    /// Expose a typed Rust function as a command in the global scope, see [`NativeFn`].
+    pub fn register_fn<A, T>(&mut self, name: &'static str, function: T)
+    where
+        T: NativeFn<A>,
+    {
+        self.supplement
+            .insert(name, Variant::COMMAND(Command::native(function)));
+    }
+
+    /// Expose a table, e.g. one filled with [`Table::register_fn`], in the global scope.
+    pub fn register_module(&mut self, name: &'static str, module: Table) {
+        self.supplement.insert(name, Variant::TABLE(module));
+    }
+
     pub fn install_code(&mut self, name: &'static str, code: String) -> Result<(), Backtrace> {
         let signal = self.run_code(String::from(name), code)?;
         match signal {
@@ -0,0 +1,415 @@
+use super::variant::boolean::Boolean;
+use super::variant::float::Float;
+use super::variant::int::Int;
+use super::variant::list::List;
+use super::variant::null::Null;
+use super::variant::represent::Represent;
+use super::variant::strand::Strand;
+use super::variant::table::Table;
+use super::variant::Variant;
+use crate::backtrace::Backtrace;
+use crate::mark::Mark;
+use crate::raise_error;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Failure while parsing JSON text, positioned at a line and a column (both 1-based).
+#[derive(Debug, Clone)]
+pub struct JsonError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+struct JsonParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+/// Parse JSON text into a variant.
+///
+/// Objects become tables, arrays become lists, and numbers become integers if they are whole
+/// and fit, or floats otherwise.
+pub fn parse(text: &str) -> Result<Variant, JsonError> {
+    let mut parser = JsonParser {
+        chars: text.chars().peekable(),
+        line: 1,
+        column: 1,
+    };
+    parser.skip_whitespace();
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.chars.peek().is_some() {
+        return Err(parser.error("Unexpected trailing characters."));
+    }
+    Ok(value)
+}
+
+impl<'a> JsonParser<'a> {
+    fn error(&self, message: &str) -> JsonError {
+        JsonError {
+            message: String::from(message),
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let current = self.chars.next()?;
+        if current == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(current)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.chars.peek() {
+            Some(&current) if current == expected => {
+                self.next();
+                Ok(())
+            }
+            Some(_) => Err(self.error(&format!("Expecting '{}'.", expected))),
+            None => Err(self.error(&format!("Expecting '{}', but the text ends.", expected))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&current) = self.chars.peek() {
+            if !matches!(current, ' ' | '\t' | '\n' | '\r') {
+                break;
+            }
+            self.next();
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Variant, JsonError> {
+        match self.chars.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => Ok(Variant::STRAND(Strand::from(self.parse_string()?))),
+            Some('t') => self.parse_literal("true", Variant::BOOL(Boolean::from(true))),
+            Some('f') => self.parse_literal("false", Variant::BOOL(Boolean::from(false))),
+            Some('n') => self.parse_literal("null", Variant::NULL(Null())),
+            Some(&current) if current == '-' || current.is_ascii_digit() => self.parse_number(),
+            Some(_) => Err(self.error("Unexpected character.")),
+            None => Err(self.error("Expecting a value, but the text ends.")),
+        }
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Variant) -> Result<Variant, JsonError> {
+        for expected in literal.chars() {
+            if self.chars.peek() != Some(&expected) {
+                return Err(self.error(&format!("Expecting '{}'.", literal)));
+            }
+            self.next();
+        }
+        Ok(value)
+    }
+
+    fn is_digit_next(&mut self) -> bool {
+        self.chars
+            .peek()
+            .is_some_and(|current| current.is_ascii_digit())
+    }
+
+    /// Consume the next character if it is one of `expected`.
+    fn next_if_any(&mut self, expected: &[char]) -> Option<char> {
+        let current = *self.chars.peek()?;
+        if !expected.contains(&current) {
+            return None;
+        }
+        self.next();
+        Some(current)
+    }
+
+    fn push_digits(&mut self, text: &mut String) {
+        while self.is_digit_next() {
+            text.push(self.next().unwrap());
+        }
+    }
+
+    /// Parse a number as `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?` (RFC 8259).
+    fn parse_number(&mut self) -> Result<Variant, JsonError> {
+        let (line, column) = (self.line, self.column);
+        let mut text = String::new();
+        if let Some(minus) = self.next_if_any(&['-']) {
+            text.push(minus);
+        }
+        if let Some(zero) = self.next_if_any(&['0']) {
+            text.push(zero);
+            if self.is_digit_next() {
+                return Err(self.error("Leading zeros are not allowed."));
+            }
+        } else if self.is_digit_next() {
+            self.push_digits(&mut text);
+        } else {
+            return Err(self.error("Expecting a digit."));
+        }
+        let mut is_whole = true;
+        if let Some(point) = self.next_if_any(&['.']) {
+            is_whole = false;
+            text.push(point);
+            if !self.is_digit_next() {
+                return Err(self.error("Expecting a digit after '.'."));
+            }
+            self.push_digits(&mut text);
+        }
+        if let Some(exponent) = self.next_if_any(&['e', 'E']) {
+            is_whole = false;
+            text.push(exponent);
+            if let Some(sign) = self.next_if_any(&['+', '-']) {
+                text.push(sign);
+            }
+            if !self.is_digit_next() {
+                return Err(self.error("Expecting a digit in the exponent."));
+            }
+            self.push_digits(&mut text);
+        }
+        if is_whole {
+            if let Ok(number) = text.parse::<i64>() {
+                return Ok(Variant::INT(Int::from(number)));
+            }
+        }
+        match text.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(Variant::FLOAT(Float::from(number))),
+            _ => Err(JsonError {
+                message: format!("Invalid number '{}'.", text),
+                line,
+                column,
+            }),
+        }
+    }
+
+    fn parse_hex(&mut self) -> Result<u32, JsonError> {
+        let mut code = 0u32;
+        for _ in 0..4 {
+            let digit = match self.chars.peek().and_then(|current| current.to_digit(16)) {
+                Some(digit) => digit,
+                None => return Err(self.error("Expecting 4 hexadecimal digits.")),
+            };
+            self.next();
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.expect('"')?;
+        let mut result = String::new();
+        loop {
+            let current = match self.chars.peek() {
+                Some(&current) => current,
+                None => return Err(self.error("Unterminated string.")),
+            };
+            if current.is_control() && current != '\u{7f}' {
+                return Err(self.error("Control character in string."));
+            }
+            self.next();
+            match current {
+                '"' => return Ok(result),
+                '\\' => {
+                    let escaped = match self.chars.peek() {
+                        Some(&escaped) => escaped,
+                        None => return Err(self.error("Unterminated string.")),
+                    };
+                    match escaped {
+                        '"' | '\\' | '/' => result.push(escaped),
+                        'b' => result.push('\u{8}'),
+                        'f' => result.push('\u{c}'),
+                        'n' => result.push('\n'),
+                        'r' => result.push('\r'),
+                        't' => result.push('\t'),
+                        'u' => {
+                            self.next();
+                            let mut code = self.parse_hex()?;
+                            if (0xD800..0xDC00).contains(&code) {
+                                // High surrogate, it must be followed by a low surrogate.
+                                self.expect('\\')?;
+                                self.expect('u')?;
+                                let low = self.parse_hex()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("Invalid low surrogate."));
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            match char::from_u32(code) {
+                                Some(character) => result.push(character),
+                                None => return Err(self.error("Invalid unicode escape.")),
+                            }
+                            continue;
+                        }
+                        _ => return Err(self.error("Invalid escape sequence.")),
+                    }
+                    self.next();
+                }
+                _ => result.push(current),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Variant, JsonError> {
+        self.expect('[')?;
+        let mut elements: Vec<Variant> = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.next();
+            return Ok(Variant::LIST(List::from(elements)));
+        }
+        loop {
+            self.skip_whitespace();
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                Some(']') => {
+                    self.next();
+                    return Ok(Variant::LIST(List::from(elements)));
+                }
+                _ => return Err(self.error("Expecting ',' or ']'.")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Variant, JsonError> {
+        self.expect('{')?;
+        let mut entries: Vec<(String, Variant)> = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.next();
+            return Ok(Variant::TABLE(Table::from(entries)));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            let value = self.parse_value()?;
+            entries.push((key, value));
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some(',') => {
+                    self.next();
+                }
+                Some('}') => {
+                    self.next();
+                    return Ok(Variant::TABLE(Table::from(entries)));
+                }
+                _ => return Err(self.error("Expecting ',' or '}'.")),
+            }
+        }
+    }
+}
+
+/// Write the variant as JSON text, pretty printed when `indent` is given.
+pub fn stringify(
+    variant: &Variant,
+    indent: Option<usize>,
+    mark: Option<Mark>,
+) -> Result<String, Backtrace> {
+    let mut result = String::new();
+    write_value(&mut result, variant, indent, 0, mark)?;
+    Ok(result)
+}
+
+fn write_string(result: &mut String, string: &str) {
+    result.push('"');
+    for current in string.chars() {
+        match current {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{8}' => result.push_str("\\b"),
+            '\u{c}' => result.push_str("\\f"),
+            _ if (current as u32) < 0x20 => {
+                result.push_str(&format!("\\u{:04x}", current as u32));
+            }
+            _ => result.push(current),
+        }
+    }
+    result.push('"');
+}
+
+fn write_newline(result: &mut String, indent: Option<usize>, depth: usize) {
+    if let Some(indent) = indent {
+        result.push('\n');
+        result.push_str(&" ".repeat(indent * depth));
+    }
+}
+
+fn write_value(
+    result: &mut String,
+    variant: &Variant,
+    indent: Option<usize>,
+    depth: usize,
+    mark: Option<Mark>,
+) -> Result<(), Backtrace> {
+    match variant {
+        Variant::NULL(_) => result.push_str("null"),
+        Variant::BOOL(boolean) => result.push_str(if boolean.is_true() { "true" } else { "false" }),
+        Variant::INT(int) => result.push_str(&format!("{}", Into::<i64>::into(*int))),
+        Variant::FLOAT(float) => {
+            let number: f64 = (*float).into();
+            if !number.is_finite() {
+                raise_error!(mark, "`{}` cannot be converted into JSON.", number);
+            }
+            result.push_str(&format!("{}", number));
+        }
+        Variant::STRAND(strand) => write_string(result, strand.as_str()),
+        Variant::LIST(list) => {
+            let elements = list.to_vec(mark.clone())?;
+            if elements.is_empty() {
+                result.push_str("[]");
+                return Ok(());
+            }
+            result.push('[');
+            for (i, element) in elements.iter().enumerate() {
+                if i != 0 {
+                    result.push(',');
+                }
+                write_newline(result, indent, depth + 1);
+                write_value(result, element, indent, depth + 1, mark.clone())?;
+            }
+            write_newline(result, indent, depth);
+            result.push(']');
+        }
+        Variant::TABLE(table) => {
+            let entries = table.entries(mark.clone())?;
+            if entries.is_empty() {
+                result.push_str("{}");
+                return Ok(());
+            }
+            result.push('{');
+            for (i, (key, value)) in entries.iter().enumerate() {
+                if i != 0 {
+                    result.push(',');
+                }
+                write_newline(result, indent, depth + 1);
+                write_string(result, key);
+                result.push(':');
+                if indent.is_some() {
+                    result.push(' ');
+                }
+                write_value(result, value, indent, depth + 1, mark.clone())?;
+            }
+            write_newline(result, indent, depth);
+            result.push('}');
+        }
+        Variant::COMMAND(_) | Variant::CLOSURE(_) => {
+            raise_error!(
+                mark.clone(),
+                "`{}` cannot be converted into JSON.",
+                variant.represent(mark.clone())?
+            );
+        }
+    }
+    Ok(())
+}
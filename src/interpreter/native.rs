@@ -0,0 +1,92 @@
+use super::context::Context;
+use super::signal::Signal;
+use super::variant::Variant;
+use crate::backtrace::Backtrace;
+use crate::log::Log;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+use std::fmt::Display;
+
+/// Rust function that can be exposed to Blinklet as a command.
+///
+/// It is implemented for functions taking up to 6 arguments, where each argument implements
+/// `TryFrom<Variant>` and the returned value implements `Into<Variant>`, e.g.
+/// `|a: f64, b: String| -> Result<Variant, String>`.
+pub trait NativeFn<Arguments>: 'static {
+    fn call_native(
+        &self,
+        context: &mut Context,
+        head: &Atom,
+        body: &[Atom],
+    ) -> Result<Signal, Backtrace>;
+}
+
+/// Resolve the atom and convert it into the argument type, marking the atom on failure.
+fn resolve_argument<T>(context: &mut Context, atom: &Atom) -> Result<T, Backtrace>
+where
+    T: TryFrom<Variant>,
+    T::Error: Into<Backtrace>,
+{
+    let variant = context.resolve_variant(atom)?;
+    match T::try_from(variant) {
+        Ok(value) => Ok(value),
+        Err(error) => {
+            // Conversions do not know the atom, so the error is raised again at the argument.
+            let backtrace: Backtrace = error.into();
+            Err(Backtrace::new(Log::error(
+                backtrace.message(),
+                Some(atom.mark.clone()),
+            )))
+        }
+    }
+}
+
+macro_rules! impl_native_fn {
+    ($count:expr $(, $argument:ident $value:ident)*) => {
+        impl<F, R, E $(, $argument)*> NativeFn<($($argument,)*)> for F
+        where
+            F: Fn($($argument),*) -> Result<R, E> + 'static,
+            R: Into<Variant>,
+            E: Display,
+            $(
+                $argument: TryFrom<Variant>,
+                <$argument as TryFrom<Variant>>::Error: Into<Backtrace>,
+            )*
+        {
+            #[allow(unused_variables, unused_mut)]
+            fn call_native(
+                &self,
+                context: &mut Context,
+                head: &Atom,
+                body: &[Atom],
+            ) -> Result<Signal, Backtrace> {
+                if body.len() != $count {
+                    raise_error!(
+                        Some(head.mark.clone()),
+                        "Expecting {} argument(s), but {} is given.",
+                        $count,
+                        body.len()
+                    );
+                }
+                let mut atoms = body.iter();
+                $(
+                    let $value: $argument = resolve_argument(context, atoms.next().unwrap())?;
+                )*
+                match (self)($($value),*) {
+                    Ok(value) => Ok(Signal::COMPLETE(value.into())),
+                    Err(error) => {
+                        raise_error!(Some(head.mark.clone()), "{}", error);
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_native_fn!(0);
+impl_native_fn!(1, A a);
+impl_native_fn!(2, A a, B b);
+impl_native_fn!(3, A a, B b, C c);
+impl_native_fn!(4, A a, B b, C c, D d);
+impl_native_fn!(5, A a, B b, C c, D d, E2 e);
+impl_native_fn!(6, A a, B b, C c, D d, E2 e, F2 f);
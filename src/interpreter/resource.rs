@@ -1,17 +1,30 @@
+pub mod archive_resource;
+pub mod chain_resource;
+pub mod embedded_resource;
+pub mod in_memory_resource;
 pub mod system_resource;
 
 use crate::{backtrace::Backtrace, raise_error};
 use std::path::{Path, PathBuf, MAIN_SEPARATOR_STR};
 
 pub const SEPERATOR_STR: &'static str = "::";
+/// Extension of scripts, added to the imports without one.
+pub const SCRIPT_EXTENSION_STR: &str = "k";
 
 pub trait Resource {
+    /// Locate the script at `path`, which is relative to the prefix.
+    fn resolve(&mut self, path: ResourcePath) -> Result<ResourcePath, Backtrace> {
+        let mut resolved = self.get_prefix().clone();
+        resolved.append(&mut path.with_default_extension())?;
+        Ok(resolved)
+    }
+    /// Get the code of the script at `path` given by [`Self::resolve`].
     fn get_code(&mut self, path: ResourcePath) -> Result<String, Backtrace>;
     fn set_prefix(&mut self, path: ResourcePath);
     fn get_prefix<'a>(&'a self) -> &'a ResourcePath;
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ResourcePath(Vec<String>);
 
 impl Default for ResourcePath {
@@ -94,6 +107,29 @@ impl ResourcePath {
         self.simplify()
     }
 
+    /// The path with [`SCRIPT_EXTENSION_STR`] added to its last component if it has no extension.
+    pub fn with_default_extension(mut self) -> ResourcePath {
+        if let Some(last) = self.0.last_mut() {
+            if !last.is_empty() && !last.contains('.') {
+                last.push('.');
+                last.push_str(SCRIPT_EXTENSION_STR);
+            }
+        }
+        self
+    }
+
+    /// Whether `root` is this path or one of its parents.
+    pub fn starts_with(&self, root: &ResourcePath) -> bool {
+        self.0.starts_with(&root.0)
+    }
+
+    /// This path relative to `root`, if it is inside of `root`.
+    pub fn strip_prefix(&self, root: &ResourcePath) -> Option<ResourcePath> {
+        self.0
+            .strip_prefix(root.0.as_slice())
+            .map(|components| ResourcePath(components.to_vec()))
+    }
+
     pub fn remove_parent_path(&mut self) -> ResourcePath {
         ResourcePath(if self.0.len() <= 1 {
             Vec::new()
@@ -0,0 +1,135 @@
+mod inflate;
+mod tar;
+mod zip;
+
+use super::in_memory_resource::InMemoryResource;
+use super::{Resource, ResourcePath, SEPERATOR_STR};
+use crate::backtrace::Backtrace;
+use crate::raise_error;
+use std::fs::{canonicalize, read};
+use std::path::{Path, PathBuf};
+
+/// Separator of the directories in the names of archive entries.
+const ENTRY_SEPERATOR_STR: &str = "/";
+
+/// Resource serving the scripts packaged in a zip, tar or gzipped tar archive.
+///
+/// The archive is read once when opened. Entries are resolved to the path of the archive followed
+/// by their path inside the archive, and looked up relative to the prefix first, then from the
+/// root of the archive.
+pub struct ArchiveResource {
+    archive: PathBuf,
+    /// Path of the archive, which the resolved paths start with.
+    root: ResourcePath,
+    prefix: ResourcePath,
+    scripts: InMemoryResource,
+}
+
+impl Resource for ArchiveResource {
+    fn resolve(&mut self, path: ResourcePath) -> Result<ResourcePath, Backtrace> {
+        // A prefix outside of the archive, e.g. of a script on the filesystem, looks up from the
+        // root of the archive.
+        self.scripts
+            .set_prefix(self.prefix.strip_prefix(&self.root).unwrap_or_default());
+        match self.scripts.resolve(path) {
+            Ok(mut path) => {
+                let mut resolved = self.root.clone();
+                resolved.append(&mut path)?;
+                Ok(resolved)
+            }
+            Err(error) => {
+                raise_error!(
+                    None,
+                    "{}\nin archive '{}'.",
+                    error.message(),
+                    self.archive.display()
+                );
+            }
+        }
+    }
+
+    fn get_code(&mut self, path: ResourcePath) -> Result<String, Backtrace> {
+        match path.strip_prefix(&self.root) {
+            Some(path) => self.scripts.get_code(path),
+            None => {
+                raise_error!(
+                    None,
+                    "Unable to fetch code '{}'.",
+                    Into::<String>::into(path)
+                );
+            }
+        }
+    }
+
+    fn get_prefix(&self) -> &ResourcePath {
+        &self.prefix
+    }
+
+    fn set_prefix(&mut self, path: ResourcePath) {
+        self.prefix = path;
+    }
+}
+
+impl ArchiveResource {
+    /// Path of the archive, which the paths resolved in the archive start with.
+    pub fn root(&self) -> &ResourcePath {
+        &self.root
+    }
+
+    /// Read the archive at `archive`, the format is told by its content.
+    ///
+    /// Entries that are not UTF-8 text are left out, as they cannot be scripts.
+    pub fn open(archive: &Path) -> Result<Self, Backtrace> {
+        let data = match read(archive) {
+            Ok(data) => data,
+            Err(_) => {
+                raise_error!(None, "Unable to read archive '{}'.", archive.display());
+            }
+        };
+        let result = if zip::is_zip(&data) {
+            zip::read_zip(&data)
+        } else if tar::is_tar(&data) {
+            tar::read_tar(&data)
+        } else if data.starts_with(&[0x1f, 0x8b]) {
+            inflate::gunzip(&data).and_then(|data| tar::read_tar(&data))
+        } else {
+            raise_error!(
+                None,
+                "'{}' is not a zip, tar or gzipped tar archive.",
+                archive.display()
+            );
+        };
+        let files = match result {
+            Ok(files) => files,
+            Err(error) => {
+                raise_error!(
+                    None,
+                    "Unable to read archive '{}': {}",
+                    archive.display(),
+                    error.message()
+                );
+            }
+        };
+
+        let mut scripts = InMemoryResource::default();
+        for (name, content) in files {
+            let code = match String::from_utf8(content) {
+                Ok(code) => code,
+                Err(_) => continue,
+            };
+            let path = name
+                .split(ENTRY_SEPERATOR_STR)
+                .filter(|component| !component.is_empty())
+                .collect::<Vec<&str>>()
+                .join(SEPERATOR_STR);
+            scripts.insert(ResourcePath::try_from(path)?, code);
+        }
+        let root = canonicalize(archive).unwrap_or(archive.to_path_buf());
+        Ok(ArchiveResource {
+            archive: archive.to_path_buf(),
+            root: ResourcePath::try_from(root)?,
+            prefix: ResourcePath::default(),
+            scripts,
+        })
+    }
+}
@@ -0,0 +1,413 @@
+use crate::backtrace::Backtrace;
+use crate::raise_error;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// Order in which the code lengths of the code length alphabet are given.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+const MAX_BITS: usize = 15;
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    buffer: u32,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    /// Read `n` bits, least significant bit first.
+    fn bits(&mut self, n: u32) -> Result<u32, Backtrace> {
+        while self.count < n {
+            let byte = match self.data.get(self.position) {
+                Some(&byte) => byte,
+                None => {
+                    raise_error!(None, "Compressed data ends unexpectedly.");
+                }
+            };
+            self.position += 1;
+            self.buffer |= (byte as u32) << self.count;
+            self.count += 8;
+        }
+        let value = self.buffer & ((1u32 << n) - 1);
+        self.buffer = if n == 32 { 0 } else { self.buffer >> n };
+        self.count -= n;
+        Ok(value)
+    }
+
+    /// Drop the bits left in the current byte.
+    fn align(&mut self) {
+        self.buffer = 0;
+        self.count = 0;
+    }
+}
+
+/// Canonical Huffman code, as the number of codes of each length and the symbols ordered by code.
+struct Huffman {
+    counts: [u16; MAX_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Huffman {
+        let mut counts = [0u16; MAX_BITS + 1];
+        for &length in lengths.iter() {
+            counts[length as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut offsets = [0u16; MAX_BITS + 2];
+        for length in 1..=MAX_BITS {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+        Huffman { counts, symbols }
+    }
+
+    fn decode(&self, reader: &mut BitReader<'_>) -> Result<u16, Backtrace> {
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+        for length in 1..=MAX_BITS {
+            code |= reader.bits(1)? as i32;
+            let count = self.counts[length] as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        raise_error!(None, "Invalid Huffman code in compressed data.");
+    }
+}
+
+/// Raise an error if writing `length` more bytes to `output` goes past `limit`.
+fn check_limit(output: &[u8], length: usize, limit: usize) -> Result<(), Backtrace> {
+    if output.len() + length > limit {
+        raise_error!(
+            None,
+            "Decompressed data is larger than the expected {} bytes.",
+            limit
+        );
+    }
+    Ok(())
+}
+
+/// Decompress raw DEFLATE data (RFC 1951), raising an error as soon as the output would be longer
+/// than `limit` bytes.
+pub fn inflate(data: &[u8], limit: usize) -> Result<Vec<u8>, Backtrace> {
+    let mut reader = BitReader {
+        data,
+        position: 0,
+        buffer: 0,
+        count: 0,
+    };
+    let mut output: Vec<u8> = Vec::new();
+    loop {
+        let is_last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => inflate_stored(&mut reader, &mut output, limit)?,
+            1 => {
+                let (literals, distances) = fixed_codes();
+                inflate_codes(&mut reader, &mut output, limit, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut reader)?;
+                inflate_codes(&mut reader, &mut output, limit, &literals, &distances)?;
+            }
+            _ => {
+                raise_error!(None, "Invalid block type in compressed data.");
+            }
+        }
+        if is_last {
+            return Ok(output);
+        }
+    }
+}
+
+fn inflate_stored(
+    reader: &mut BitReader<'_>,
+    output: &mut Vec<u8>,
+    limit: usize,
+) -> Result<(), Backtrace> {
+    reader.align();
+    let length = reader.bits(16)?;
+    let complement = reader.bits(16)?;
+    if length != !complement & 0xffff {
+        raise_error!(None, "Invalid stored block length in compressed data.");
+    }
+    let start = reader.position;
+    let end = start + length as usize;
+    if end > reader.data.len() {
+        raise_error!(None, "Compressed data ends unexpectedly.");
+    }
+    check_limit(output, length as usize, limit)?;
+    output.extend_from_slice(&reader.data[start..end]);
+    reader.position = end;
+    Ok(())
+}
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    for (symbol, length) in lengths.iter_mut().enumerate() {
+        *length = match symbol {
+            0..=143 => 8,
+            144..=255 => 9,
+            256..=279 => 7,
+            _ => 8,
+        };
+    }
+    (Huffman::new(&lengths), Huffman::new(&[5u8; 30]))
+}
+
+fn dynamic_codes(reader: &mut BitReader<'_>) -> Result<(Huffman, Huffman), Backtrace> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        raise_error!(None, "Invalid code counts in compressed data.");
+    }
+
+    let mut code_lengths = [0u8; 19];
+    for &symbol in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_lengths[symbol] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths);
+
+    // Literal/length and distance code lengths are given as one sequence.
+    let mut lengths: Vec<u8> = Vec::with_capacity(literal_count + distance_count);
+    while lengths.len() < literal_count + distance_count {
+        let symbol = code_length_code.decode(reader)?;
+        let (length, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => match lengths.last() {
+                Some(&previous) => (previous, 3 + reader.bits(2)?),
+                None => {
+                    raise_error!(None, "Repeated code length without a previous one.");
+                }
+            },
+            17 => (0, 3 + reader.bits(3)?),
+            _ => (0, 11 + reader.bits(7)?),
+        };
+        if lengths.len() + repeat as usize > literal_count + distance_count {
+            raise_error!(None, "Too many code lengths in compressed data.");
+        }
+        lengths.extend(std::iter::repeat_n(length, repeat as usize));
+    }
+    if lengths[256] == 0 {
+        raise_error!(None, "Missing end of block code in compressed data.");
+    }
+    Ok((
+        Huffman::new(&lengths[..literal_count]),
+        Huffman::new(&lengths[literal_count..]),
+    ))
+}
+
+fn inflate_codes(
+    reader: &mut BitReader<'_>,
+    output: &mut Vec<u8>,
+    limit: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<(), Backtrace> {
+    loop {
+        let symbol = literals.decode(reader)? as usize;
+        if symbol < 256 {
+            check_limit(output, 1, limit)?;
+            output.push(symbol as u8);
+            continue;
+        }
+        if symbol == 256 {
+            return Ok(());
+        }
+        let symbol = symbol - 257;
+        if symbol >= LENGTH_BASE.len() {
+            raise_error!(None, "Invalid length code in compressed data.");
+        }
+        let length =
+            LENGTH_BASE[symbol] as usize + reader.bits(LENGTH_EXTRA[symbol] as u32)? as usize;
+        let symbol = distances.decode(reader)? as usize;
+        if symbol >= DISTANCE_BASE.len() {
+            raise_error!(None, "Invalid distance code in compressed data.");
+        }
+        let distance =
+            DISTANCE_BASE[symbol] as usize + reader.bits(DISTANCE_EXTRA[symbol] as u32)? as usize;
+        if distance > output.len() {
+            raise_error!(
+                None,
+                "Distance reaches before the start of compressed data."
+            );
+        }
+        check_limit(output, length, limit)?;
+        // The copy may overlap with what it produces, so it goes byte by byte.
+        let start = output.len() - distance;
+        for i in 0..length {
+            output.push(output[start + i]);
+        }
+    }
+}
+
+/// Decompress gzip data (RFC 1952), without checking its checksum. The output is limited to the
+/// size given by the trailer.
+pub fn gunzip(data: &[u8]) -> Result<Vec<u8>, Backtrace> {
+    const FLAG_HCRC: u8 = 0x02;
+    const FLAG_EXTRA: u8 = 0x04;
+    const FLAG_NAME: u8 = 0x08;
+    const FLAG_COMMENT: u8 = 0x10;
+
+    if data.len() < 18 || data[0] != 0x1f || data[1] != 0x8b || data[2] != 8 {
+        raise_error!(None, "Invalid gzip header.");
+    }
+    let flags = data[3];
+    let mut position = 10;
+    if flags & FLAG_EXTRA != 0 {
+        if position + 2 > data.len() {
+            raise_error!(None, "Invalid gzip header.");
+        }
+        position += 2 + u16::from_le_bytes([data[position], data[position + 1]]) as usize;
+    }
+    for flag in [FLAG_NAME, FLAG_COMMENT] {
+        if flags & flag != 0 {
+            match data.iter().skip(position).position(|&byte| byte == 0) {
+                Some(length) => position += length + 1,
+                None => {
+                    raise_error!(None, "Invalid gzip header.");
+                }
+            }
+        }
+    }
+    if flags & FLAG_HCRC != 0 {
+        position += 2;
+    }
+    // The trailer is the checksum and the size of the decompressed data, modulo 2^32.
+    let trailer = data.len() - 8;
+    if position > trailer {
+        raise_error!(None, "Invalid gzip header.");
+    }
+    let size = u32::from_le_bytes([
+        data[trailer + 4],
+        data[trailer + 5],
+        data[trailer + 6],
+        data[trailer + 7],
+    ]) as usize;
+    let output = inflate(&data[position..trailer], size)?;
+    if output.len() != size {
+        raise_error!(
+            None,
+            "Decompressed data does not match the size in the gzip trailer."
+        );
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gunzip, inflate};
+
+    /// Stored block of "hello".
+    const STORED: [u8; 10] = [0x01, 0x05, 0x00, 0xfa, 0xff, b'h', b'e', b'l', b'l', b'o'];
+    /// Fixed block of "a" repeated 20 times, a literal then a copy overlapping its own output.
+    const FIXED: [u8; 5] = [0x4b, 0x4c, 0xc4, 0x04, 0x00];
+    /// Fixed block of "abc" repeated 5 times.
+    const FIXED_REPEAT: [u8; 7] = [0x4b, 0x4c, 0x4a, 0x4e, 0x44, 0x42, 0x00];
+    /// Dynamic block of the lines given by [`dynamic_text`].
+    const DYNAMIC: [u8; 77] = [
+        0x6d, 0xcf, 0x3b, 0x0a, 0x80, 0x40, 0x10, 0x04, 0xd1, 0xdc, 0x53, 0xcc, 0x11, 0x6c, 0xff,
+        0x5e, 0x47, 0x58, 0x61, 0x71, 0x58, 0x13, 0xef, 0x8f, 0x18, 0x4a, 0x57, 0xfa, 0xa2, 0xaa,
+        0xac, 0xad, 0x44, 0x1f, 0xf7, 0x19, 0x47, 0xd6, 0x76, 0x65, 0x79, 0xba, 0xfc, 0x48, 0x4e,
+        0x83, 0xd3, 0xe8, 0x34, 0x39, 0xcd, 0x4e, 0x8b, 0xd3, 0xea, 0xb4, 0x39, 0xed, 0x90, 0x4a,
+        0xf9, 0xd0, 0x2f, 0x18, 0x10, 0x1c, 0x08, 0x16, 0x04, 0x0f, 0x82, 0x09, 0xc1, 0x85, 0xfe,
+        0x1b, 0x2f,
+    ];
+
+    fn dynamic_text() -> Vec<u8> {
+        (0..19)
+            .map(|i| format!("line {} of blinklet\n", i))
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    fn gzip(deflated: &[u8], size: u32) -> Vec<u8> {
+        let mut data = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff];
+        data.extend_from_slice(deflated);
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&size.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn inflates_stored_blocks() {
+        assert_eq!(inflate(&STORED, 5).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn inflates_fixed_blocks_with_overlapping_copies() {
+        assert_eq!(inflate(&FIXED, 20).unwrap(), vec![b'a'; 20]);
+        assert_eq!(inflate(&FIXED_REPEAT, 15).unwrap(), b"abcabcabcabcabc");
+    }
+
+    #[test]
+    fn inflates_dynamic_blocks() {
+        let text = dynamic_text();
+        assert_eq!(inflate(&DYNAMIC, text.len()).unwrap(), text);
+    }
+
+    #[test]
+    fn stops_at_the_limit() {
+        let error = inflate(&FIXED, 10).unwrap_err();
+        assert!(error
+            .message()
+            .contains("larger than the expected 10 bytes"));
+        assert!(inflate(&STORED, 4).is_err());
+        assert!(inflate(&DYNAMIC, 100).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        for data in [&STORED[..7], &FIXED[..3], &DYNAMIC[..40]] {
+            assert!(inflate(data, 1000).is_err());
+        }
+        assert!(inflate(&[], 1000).is_err());
+    }
+
+    #[test]
+    fn rejects_corrupt_data() {
+        // Reserved block type.
+        assert!(inflate(&[0x07], 1000).is_err());
+        // Stored length not matching its complement.
+        assert!(inflate(&[0x01, 0x05, 0x00, 0x00, 0x00, b'h'], 1000).is_err());
+        // Fixed block starting with a copy, which reaches before the start of the output.
+        let error = inflate(&[0x03, 0x02, 0x00], 1000).unwrap_err();
+        assert!(error.message().contains("before the start"));
+    }
+
+    #[test]
+    fn gunzips_up_to_the_size_in_the_trailer() {
+        let text = dynamic_text();
+        let data = gzip(&DYNAMIC, text.len() as u32);
+        assert_eq!(gunzip(&data).unwrap(), text);
+        assert!(gunzip(&gzip(&DYNAMIC, 100)).is_err());
+        assert!(gunzip(&gzip(&DYNAMIC, text.len() as u32 + 1)).is_err());
+        assert!(gunzip(&data[..data.len() - 20]).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::backtrace::Backtrace;
+use crate::raise_error;
+
+const BLOCK_SIZE: usize = 512;
+const MAGIC_OFFSET: usize = 257;
+const MAGIC: &[u8] = b"ustar";
+
+pub fn is_tar(data: &[u8]) -> bool {
+    data.get(MAGIC_OFFSET..MAGIC_OFFSET + MAGIC.len()) == Some(MAGIC)
+}
+
+/// Text of a field, which ends at the first NUL byte.
+fn field(header: &[u8], start: usize, length: usize) -> String {
+    let bytes = &header[start..start + length];
+    let end = bytes.iter().position(|&byte| byte == 0).unwrap_or(length);
+    String::from_utf8_lossy(&bytes[..end]).to_string()
+}
+
+fn octal_field(header: &[u8], start: usize, length: usize) -> Result<usize, Backtrace> {
+    let text = field(header, start, length);
+    let text = text.trim_matches(|c: char| c == ' ' || c == '\0');
+    if text.is_empty() {
+        return Ok(0);
+    }
+    match usize::from_str_radix(text, 8) {
+        Ok(value) => Ok(value),
+        Err(_) => {
+            raise_error!(None, "Invalid number '{}' in the tar archive.", text);
+        }
+    }
+}
+
+/// Path given by a pax extended header, if any.
+fn pax_path(content: &[u8]) -> Option<String> {
+    // Records are `<length> <key>=<value>\n`.
+    let text = String::from_utf8_lossy(content);
+    text.lines().find_map(|record| {
+        let (_, pair) = record.split_once(' ')?;
+        let (key, value) = pair.split_once('=')?;
+        (key == "path").then(|| String::from(value))
+    })
+}
+
+/// Read the regular files of a tar archive as pairs of name and content.
+pub fn read_tar(data: &[u8]) -> Result<Vec<(String, Vec<u8>)>, Backtrace> {
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut long_name: Option<String> = None;
+    let mut offset = 0;
+    while offset + BLOCK_SIZE <= data.len() {
+        let header = &data[offset..offset + BLOCK_SIZE];
+        if header.iter().all(|&byte| byte == 0) {
+            break;
+        }
+        let size = octal_field(header, 124, 12)?;
+        let kind = header[156];
+        let start = offset + BLOCK_SIZE;
+        let content = match data.get(start..start + size) {
+            Some(content) => content,
+            None => {
+                raise_error!(None, "Tar archive ends unexpectedly.");
+            }
+        };
+        offset = start + size.div_ceil(BLOCK_SIZE) * BLOCK_SIZE;
+
+        match kind {
+            // GNU long name and pax extended header, both name the following entry.
+            b'L' => {
+                let end = content.iter().position(|&byte| byte == 0).unwrap_or(size);
+                long_name = Some(String::from_utf8_lossy(&content[..end]).to_string());
+            }
+            b'x' => long_name = pax_path(content).or(long_name),
+            b'0' | b'\0' => {
+                let name = match long_name.take() {
+                    Some(name) => name,
+                    None => {
+                        let prefix = field(header, 345, 155);
+                        let name = field(header, 0, 100);
+                        if is_tar(header) && !prefix.is_empty() {
+                            format!("{}/{}", prefix, name)
+                        } else {
+                            name
+                        }
+                    }
+                };
+                files.push((name, content.to_vec()));
+            }
+            _ => long_name = None,
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_tar, read_tar, BLOCK_SIZE, MAGIC, MAGIC_OFFSET};
+
+    fn header(name: &str, size: usize, kind: u8) -> Vec<u8> {
+        let mut header = vec![0u8; BLOCK_SIZE];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        header[124..135].copy_from_slice(format!("{:011o}", size).as_bytes());
+        header[156] = kind;
+        header[MAGIC_OFFSET..MAGIC_OFFSET + MAGIC.len()].copy_from_slice(MAGIC);
+        header
+    }
+
+    fn entry(name: &str, kind: u8, content: &[u8]) -> Vec<u8> {
+        let mut data = header(name, content.len(), kind);
+        data.extend_from_slice(content);
+        data.resize(data.len().div_ceil(BLOCK_SIZE) * BLOCK_SIZE, 0);
+        data
+    }
+
+    fn tar(entries: &[Vec<u8>]) -> Vec<u8> {
+        let mut data = entries.concat();
+        data.extend_from_slice(&[0; BLOCK_SIZE * 2]);
+        data
+    }
+
+    #[test]
+    fn reads_regular_files() {
+        let data = tar(&[
+            entry("app/", b'5', b""),
+            entry("app/main.k", b'0', b"println 'hi'\n"),
+        ]);
+        assert!(is_tar(&data));
+        assert_eq!(
+            read_tar(&data).unwrap(),
+            vec![(String::from("app/main.k"), b"println 'hi'\n".to_vec())]
+        );
+    }
+
+    #[test]
+    fn reads_gnu_long_names() {
+        let name = format!("app/{}.k", "long".repeat(40));
+        let mut long_name = name.clone().into_bytes();
+        long_name.push(0);
+        let data = tar(&[
+            entry("././@LongLink", b'L', &long_name),
+            entry("app/truncated", b'0', b"var x 1\n"),
+            entry("app/short.k", b'0', b""),
+        ]);
+        let files = read_tar(&data).unwrap();
+        assert_eq!(files[0], (name, b"var x 1\n".to_vec()));
+        assert_eq!(files[1].0, "app/short.k");
+    }
+
+    #[test]
+    fn reads_pax_paths() {
+        let name = format!("app/{}.k", "pax".repeat(50));
+        let record = format!(" path={}\n", name);
+        let record = format!("{}{}", record.len() + 3, record);
+        let data = tar(&[
+            entry(
+                "PaxHeaders/x",
+                b'x',
+                format!("20 mtime=1700000000\n{}", record).as_bytes(),
+            ),
+            entry("app/truncated", b'0', b"var x 1\n"),
+        ]);
+        assert_eq!(
+            read_tar(&data).unwrap(),
+            vec![(name, b"var x 1\n".to_vec())]
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_archives() {
+        let data = entry("app/main.k", b'0', &[b'a'; 600]);
+        assert!(read_tar(&data[..BLOCK_SIZE + 100]).is_err());
+    }
+
+    #[test]
+    fn rejects_corrupt_sizes() {
+        let mut data = tar(&[entry("app/main.k", b'0', b"var x 1\n")]);
+        data[124] = b'9';
+        assert!(read_tar(&data).is_err());
+        assert!(!is_tar(&[0; BLOCK_SIZE]));
+    }
+}
@@ -0,0 +1,247 @@
+use super::inflate::inflate;
+use crate::backtrace::Backtrace;
+use crate::raise_error;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const END_SIGNATURE: u32 = 0x06054b50;
+const END_SIZE: usize = 22;
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+const FLAG_ENCRYPTED: u16 = 0x0001;
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, Backtrace> {
+    match data.get(offset..offset + 2) {
+        Some(bytes) => Ok(u16::from_le_bytes([bytes[0], bytes[1]])),
+        None => {
+            raise_error!(None, "Zip archive ends unexpectedly.");
+        }
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, Backtrace> {
+    match data.get(offset..offset + 4) {
+        Some(bytes) => Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        None => {
+            raise_error!(None, "Zip archive ends unexpectedly.");
+        }
+    }
+}
+
+pub fn is_zip(data: &[u8]) -> bool {
+    read_u32(data, 0)
+        .is_ok_and(|signature| signature == LOCAL_HEADER_SIGNATURE || signature == END_SIGNATURE)
+}
+
+/// Read the files of a zip archive as pairs of name and content, following its central directory.
+pub fn read_zip(data: &[u8]) -> Result<Vec<(String, Vec<u8>)>, Backtrace> {
+    // The end of central directory record is followed by a comment of up to 65535 bytes.
+    if data.len() < END_SIZE {
+        raise_error!(None, "Zip archive is too short.");
+    }
+    let lowest = data.len().saturating_sub(END_SIZE + u16::MAX as usize);
+    let mut end = data.len() - END_SIZE;
+    while read_u32(data, end)? != END_SIGNATURE {
+        if end == lowest {
+            raise_error!(
+                None,
+                "Unable to find the central directory of the zip archive."
+            );
+        }
+        end -= 1;
+    }
+    let count = read_u16(data, end + 10)? as usize;
+    let mut offset = read_u32(data, end + 16)? as usize;
+
+    let mut files: Vec<(String, Vec<u8>)> = Vec::with_capacity(count);
+    for _ in 0..count {
+        if read_u32(data, offset)? != CENTRAL_HEADER_SIGNATURE {
+            raise_error!(None, "Invalid central directory header in the zip archive.");
+        }
+        let flags = read_u16(data, offset + 8)?;
+        let method = read_u16(data, offset + 10)?;
+        let compressed_size = read_u32(data, offset + 20)?;
+        let size = read_u32(data, offset + 24)?;
+        let name_length = read_u16(data, offset + 28)? as usize;
+        let extra_length = read_u16(data, offset + 30)? as usize;
+        let comment_length = read_u16(data, offset + 32)? as usize;
+        let local_offset = read_u32(data, offset + 42)?;
+        let name = match data.get(offset + 46..offset + 46 + name_length) {
+            Some(name) => String::from_utf8_lossy(name).to_string(),
+            None => {
+                raise_error!(None, "Zip archive ends unexpectedly.");
+            }
+        };
+        offset += 46 + name_length + extra_length + comment_length;
+
+        if name.ends_with('/') {
+            continue;
+        }
+        if flags & FLAG_ENCRYPTED != 0 {
+            raise_error!(None, "'{}' in the zip archive is encrypted.", name);
+        }
+        if [compressed_size, size, local_offset].contains(&u32::MAX) {
+            raise_error!(
+                None,
+                "'{}' in the zip archive needs Zip64, which is not supported.",
+                name
+            );
+        }
+
+        // Sizes are taken from the central directory, the local header may leave them out.
+        let local_offset = local_offset as usize;
+        if read_u32(data, local_offset)? != LOCAL_HEADER_SIGNATURE {
+            raise_error!(
+                None,
+                "Invalid local header of '{}' in the zip archive.",
+                name
+            );
+        }
+        let start = local_offset
+            + 30
+            + read_u16(data, local_offset + 26)? as usize
+            + read_u16(data, local_offset + 28)? as usize;
+        let compressed = match data.get(start..start + compressed_size as usize) {
+            Some(compressed) => compressed,
+            None => {
+                raise_error!(None, "Zip archive ends unexpectedly.");
+            }
+        };
+        let content = match method {
+            METHOD_STORED => compressed.to_vec(),
+            METHOD_DEFLATED => inflate(compressed, size as usize)?,
+            _ => {
+                raise_error!(
+                    None,
+                    "'{}' in the zip archive uses compression method {}, which is not supported.",
+                    name,
+                    method
+                );
+            }
+        };
+        if content.len() != size as usize {
+            raise_error!(None, "'{}' in the zip archive has a wrong size.", name);
+        }
+        files.push((name, content));
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_zip, read_zip, METHOD_DEFLATED, METHOD_STORED};
+
+    /// Fixed DEFLATE block of "a" repeated 20 times.
+    const DEFLATED: [u8; 5] = [0x4b, 0x4c, 0xc4, 0x04, 0x00];
+
+    struct Entry<'a> {
+        name: &'a str,
+        method: u16,
+        data: &'a [u8],
+        size: u32,
+    }
+
+    /// Build a zip archive, leaving the sizes out of the local headers as streaming writers do.
+    fn zip(entries: &[Entry<'_>]) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        let mut central: Vec<u8> = Vec::new();
+        for entry in entries.iter() {
+            let offset = data.len() as u32;
+            data.extend_from_slice(&0x04034b50u32.to_le_bytes());
+            data.extend_from_slice(&[20, 0, 0, 0]);
+            data.extend_from_slice(&entry.method.to_le_bytes());
+            data.extend_from_slice(&[0; 16]);
+            data.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+            data.extend_from_slice(&[0, 0]);
+            data.extend_from_slice(entry.name.as_bytes());
+            data.extend_from_slice(entry.data);
+
+            central.extend_from_slice(&0x02014b50u32.to_le_bytes());
+            central.extend_from_slice(&[20, 0, 20, 0, 0, 0]);
+            central.extend_from_slice(&entry.method.to_le_bytes());
+            central.extend_from_slice(&[0; 8]);
+            central.extend_from_slice(&(entry.data.len() as u32).to_le_bytes());
+            central.extend_from_slice(&entry.size.to_le_bytes());
+            central.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+            central.extend_from_slice(&[0; 12]);
+            central.extend_from_slice(&offset.to_le_bytes());
+            central.extend_from_slice(entry.name.as_bytes());
+        }
+        let central_offset = data.len() as u32;
+        data.extend_from_slice(&central);
+        data.extend_from_slice(&0x06054b50u32.to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(central.len() as u32).to_le_bytes());
+        data.extend_from_slice(&central_offset.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+        data
+    }
+
+    fn sample() -> Vec<u8> {
+        zip(&[
+            Entry {
+                name: "lib/",
+                method: METHOD_STORED,
+                data: b"",
+                size: 0,
+            },
+            Entry {
+                name: "lib/main.k",
+                method: METHOD_STORED,
+                data: b"println 'hi'\n",
+                size: 13,
+            },
+            Entry {
+                name: "a.k",
+                method: METHOD_DEFLATED,
+                data: &DEFLATED,
+                size: 20,
+            },
+        ])
+    }
+
+    #[test]
+    fn reads_stored_and_deflated_files() {
+        let data = sample();
+        assert!(is_zip(&data));
+        let files = read_zip(&data).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                (String::from("lib/main.k"), b"println 'hi'\n".to_vec()),
+                (String::from("a.k"), vec![b'a'; 20]),
+            ]
+        );
+    }
+
+    #[test]
+    fn caps_deflated_files_to_their_size() {
+        let data = zip(&[Entry {
+            name: "a.k",
+            method: METHOD_DEFLATED,
+            data: &DEFLATED,
+            size: 8,
+        }]);
+        let error = read_zip(&data).unwrap_err();
+        assert!(error.message().contains("larger than the expected 8 bytes"));
+    }
+
+    #[test]
+    fn rejects_truncated_archives() {
+        let data = sample();
+        assert!(read_zip(&data[..10]).is_err());
+        assert!(read_zip(&data[..data.len() - 30]).is_err());
+    }
+
+    #[test]
+    fn rejects_corrupt_archives() {
+        let mut data = sample();
+        // Point the central directory at the middle of a file.
+        let offset = data.len() - 6;
+        data[offset] += 3;
+        assert!(read_zip(&data).is_err());
+        assert!(!is_zip(b"not a zip"));
+    }
+}
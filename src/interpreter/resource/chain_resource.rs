@@ -0,0 +1,78 @@
+use super::{Resource, ResourcePath};
+use crate::backtrace::Backtrace;
+use crate::raise_error;
+use std::collections::HashMap;
+
+/// Resource layering several resources, each script is served by the first one that finds it.
+#[derive(Default)]
+pub struct ChainResource {
+    prefix: ResourcePath,
+    resources: Vec<Box<dyn Resource>>,
+    /// Index of the resource that resolves each path.
+    resolved: HashMap<ResourcePath, usize>,
+}
+
+impl Resource for ChainResource {
+    fn resolve(&mut self, path: ResourcePath) -> Result<ResourcePath, Backtrace> {
+        let mut errors: Vec<String> = Vec::new();
+        for (i, resource) in self.resources.iter_mut().enumerate() {
+            match resource.resolve(path.clone()) {
+                Ok(resolved) => {
+                    self.resolved.insert(resolved.clone(), i);
+                    return Ok(resolved);
+                }
+                Err(error) => errors.push(error.message()),
+            }
+        }
+        if errors.is_empty() {
+            raise_error!(
+                None,
+                "Unable to find script '{}', there is no resource to look in.",
+                Into::<String>::into(path)
+            );
+        }
+        raise_error!(None, "{}", errors.join("\n"));
+    }
+
+    fn get_code(&mut self, path: ResourcePath) -> Result<String, Backtrace> {
+        match self.resolved.get(&path) {
+            Some(&i) => self.resources[i].get_code(path),
+            None => {
+                raise_error!(
+                    None,
+                    "Unable to fetch code '{}'.",
+                    Into::<String>::into(path)
+                );
+            }
+        }
+    }
+
+    fn get_prefix(&self) -> &ResourcePath {
+        &self.prefix
+    }
+
+    fn set_prefix(&mut self, path: ResourcePath) {
+        for resource in self.resources.iter_mut() {
+            resource.set_prefix(path.clone());
+        }
+        self.prefix = path;
+    }
+}
+
+impl From<Vec<Box<dyn Resource>>> for ChainResource {
+    fn from(value: Vec<Box<dyn Resource>>) -> Self {
+        let mut chain = ChainResource::default();
+        for resource in value {
+            chain.push(resource);
+        }
+        chain
+    }
+}
+
+impl ChainResource {
+    /// Add a resource to fall back to, after the ones added before.
+    pub fn push(&mut self, mut resource: Box<dyn Resource>) {
+        resource.set_prefix(self.prefix.clone());
+        self.resources.push(resource);
+    }
+}
@@ -0,0 +1,62 @@
+use super::{SCRIPT_EXTENSION_STR, SEPERATOR_STR};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Include the scripts written by [`bundle_directory`] into `OUT_DIR`, as a
+/// `&'static [(&'static str, &'static str)]` of path and code.
+///
+/// ```ignore
+/// let scripts: &[(&str, &str)] = blinklet::include_scripts!("scripts.rs");
+/// context.resource = Box::new(InMemoryResource::try_from(scripts)?);
+/// ```
+#[macro_export]
+macro_rules! include_scripts {
+    ($file:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $file))
+    };
+}
+
+/// Write the scripts under `directory` as Rust source to `output`, for a build script to bundle
+/// them into the binary with [`include_scripts`].
+///
+/// The scripts are keyed by their path relative to `directory`, and cargo is told to rerun the
+/// build script when any of them changes.
+// Called by build scripts of embedders, the binary never bundles scripts.
+#[allow(dead_code)]
+pub fn bundle_directory(directory: &Path, output: &Path) -> io::Result<()> {
+    let mut scripts: Vec<(String, String)> = Vec::new();
+    collect_scripts(directory, &mut Vec::new(), &mut scripts)?;
+    scripts.sort();
+
+    let mut source = String::from("&[\n");
+    for (path, file) in scripts.iter() {
+        source.push_str(&format!("    ({:?}, include_str!({:?})),\n", path, file));
+    }
+    source.push_str("]\n");
+    println!("cargo:rerun-if-changed={}", directory.display());
+    fs::write(output, source)
+}
+
+fn collect_scripts(
+    directory: &Path,
+    components: &mut Vec<String>,
+    scripts: &mut Vec<(String, String)>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = fs::canonicalize(entry.path())?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if path.is_dir() {
+            components.push(name);
+            collect_scripts(&path, components, scripts)?;
+            components.pop();
+        } else if path.extension().is_some_and(|x| x == SCRIPT_EXTENSION_STR) {
+            components.push(name);
+            scripts.push((components.join(SEPERATOR_STR), path.display().to_string()));
+            components.pop();
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+    Ok(())
+}
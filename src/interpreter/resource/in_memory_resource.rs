@@ -0,0 +1,102 @@
+use super::{Resource, ResourcePath};
+use crate::backtrace::Backtrace;
+use crate::raise_error;
+use std::collections::HashMap;
+
+/// Resource serving scripts held in memory, keyed by their path.
+#[derive(Default)]
+pub struct InMemoryResource {
+    prefix: ResourcePath,
+    scripts: HashMap<ResourcePath, String>,
+}
+
+impl Resource for InMemoryResource {
+    fn resolve(&mut self, path: ResourcePath) -> Result<ResourcePath, Backtrace> {
+        // Scripts are looked up relative to the prefix first, then from the root.
+        let path = path.with_default_extension();
+        let mut candidate = self.prefix.clone();
+        candidate.append(&mut path.clone())?;
+        if self.scripts.contains_key(&candidate) {
+            return Ok(candidate);
+        }
+        if self.scripts.contains_key(&path) {
+            return Ok(path);
+        }
+        let mut tried: Vec<String> = vec![candidate.clone().into()];
+        if candidate != path {
+            tried.push(path.clone().into());
+        }
+        raise_error!(
+            None,
+            "Unable to find script '{}', tried:\n{}",
+            Into::<String>::into(path),
+            tried
+                .iter()
+                .map(|path| format!("    {}", path))
+                .collect::<Vec<String>>()
+                .join("\n")
+        );
+    }
+
+    fn get_code(&mut self, path: ResourcePath) -> Result<String, Backtrace> {
+        match self.scripts.get(&path) {
+            Some(code) => Ok(code.clone()),
+            None => {
+                raise_error!(
+                    None,
+                    "Unable to fetch code '{}'.",
+                    Into::<String>::into(path)
+                );
+            }
+        }
+    }
+
+    fn get_prefix(&self) -> &ResourcePath {
+        &self.prefix
+    }
+
+    fn set_prefix(&mut self, path: ResourcePath) {
+        self.prefix = path;
+    }
+}
+
+impl From<HashMap<ResourcePath, String>> for InMemoryResource {
+    fn from(value: HashMap<ResourcePath, String>) -> Self {
+        InMemoryResource {
+            prefix: ResourcePath::default(),
+            scripts: value,
+        }
+    }
+}
+
+impl TryFrom<&[(&str, &str)]> for InMemoryResource {
+    type Error = Backtrace;
+
+    /// Build from pairs of path and code, such as the scripts bundled by
+    /// [`super::embedded_resource::bundle_directory`].
+    fn try_from(value: &[(&str, &str)]) -> Result<Self, Self::Error> {
+        let mut resource = InMemoryResource::default();
+        for (path, code) in value.iter() {
+            resource.insert(ResourcePath::try_from(path.to_string())?, code.to_string());
+        }
+        Ok(resource)
+    }
+}
+
+impl InMemoryResource {
+    /// Add the script at `path`, returning the code it replaces.
+    pub fn insert(&mut self, path: ResourcePath, code: String) -> Option<String> {
+        self.scripts.insert(path, code)
+    }
+
+    // Only embedders edit the scripts after building the resource.
+    #[allow(dead_code)]
+    pub fn remove(&mut self, path: &ResourcePath) -> Option<String> {
+        self.scripts.remove(path)
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, path: &ResourcePath) -> bool {
+        self.scripts.contains_key(path)
+    }
+}
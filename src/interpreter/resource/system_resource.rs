@@ -1,39 +1,72 @@
 use super::{Resource, ResourcePath};
 use crate::backtrace::Backtrace;
 use crate::raise_error;
-use std::fs::read_to_string;
+use std::env;
+use std::fs::{canonicalize, read_to_string};
 use std::path::PathBuf;
 
+/// Environment variable listing the search roots, separated like `PATH`.
+pub const PATH_VARIABLE_STR: &str = "BLINKLET_PATH";
+
 pub struct SystemResource {
     prefix: ResourcePath,
+    /// Directories probed in order when the script is not found relative to the prefix.
+    roots: Vec<ResourcePath>,
 }
 
 impl Default for SystemResource {
     fn default() -> Self {
         SystemResource {
             prefix: ResourcePath::default(),
+            roots: Vec::new(),
         }
     }
 }
 
 impl Resource for SystemResource {
-    fn get_code(&mut self, mut path: ResourcePath) -> Result<String, Backtrace> {
-        let mut resolved = self.prefix.clone();
-        let _ = resolved.append(&mut path);
-        let result = read_to_string(Into::<PathBuf>::into(resolved.clone()));
+    fn resolve(&mut self, path: ResourcePath) -> Result<ResourcePath, Backtrace> {
+        let path = path.with_default_extension();
+        let mut tried: Vec<String> = Vec::new();
+        for root in [&self.prefix].into_iter().chain(self.roots.iter()) {
+            let mut candidate = root.clone();
+            candidate.append(&mut path.clone())?;
+            let file: PathBuf = candidate.into();
+            // Scripts are known by their real path, so a symbolic link cannot make a script
+            // outside of the allowed import roots look like it is inside one.
+            if let Ok(real) = canonicalize(&file) {
+                if real.is_file() {
+                    return ResourcePath::try_from(real);
+                }
+            }
+            tried.push(file.display().to_string());
+        }
+        raise_error!(
+            None,
+            "Unable to find script '{}', tried:\n{}",
+            Into::<String>::into(path),
+            tried
+                .iter()
+                .map(|file| format!("    {}", file))
+                .collect::<Vec<String>>()
+                .join("\n")
+        );
+    }
+
+    fn get_code(&mut self, path: ResourcePath) -> Result<String, Backtrace> {
+        let result = read_to_string(Into::<PathBuf>::into(path.clone()));
         if result.is_err() {
             raise_error!(
                 None,
                 "Unable to fetch code '{}'.",
-                Into::<String>::into(resolved)
+                Into::<String>::into(path)
             );
         } else {
             Ok(result.unwrap())
         }
     }
 
-    fn get_prefix<'a>(&'a self) -> &'a ResourcePath {
-        return &self.prefix;
+    fn get_prefix(&self) -> &ResourcePath {
+        &self.prefix
     }
 
     fn set_prefix(&mut self, path: ResourcePath) {
@@ -43,6 +76,36 @@ impl Resource for SystemResource {
 
 impl From<ResourcePath> for SystemResource {
     fn from(value: ResourcePath) -> Self {
-        SystemResource { prefix: value }
+        SystemResource {
+            prefix: value,
+            roots: Vec::new(),
+        }
+    }
+}
+
+impl SystemResource {
+    /// Add a directory to search scripts in, after the ones added before.
+    pub fn add_root(&mut self, root: PathBuf) -> Result<(), Backtrace> {
+        // Roots are made absolute, so `..` in them does not escape the root of the path.
+        let root = canonicalize(&root).unwrap_or(root);
+        self.roots.push(ResourcePath::try_from(root)?);
+        Ok(())
+    }
+
+    /// Directories to search scripts in, in the order they are searched.
+    pub fn roots(&self) -> &[ResourcePath] {
+        &self.roots
+    }
+
+    /// Add the directories listed in [`PATH_VARIABLE_STR`] as roots.
+    pub fn add_roots_from_env(&mut self) -> Result<(), Backtrace> {
+        if let Some(paths) = env::var_os(PATH_VARIABLE_STR) {
+            for root in env::split_paths(&paths) {
+                if !root.as_os_str().is_empty() {
+                    self.add_root(root)?;
+                }
+            }
+        }
+        Ok(())
     }
 }
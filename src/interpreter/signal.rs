@@ -1,5 +1,9 @@
+use super::variant::represent::Represent;
 use super::variant::Variant;
+use crate::backtrace::Backtrace;
+use crate::log::Log;
 use crate::mark::Mark;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub enum Signal {
@@ -8,3 +12,17 @@ pub enum Signal {
     BREAK(Mark),
     CONTINUE(Mark),
 }
+
+/// Make an error carrying `value`, so `try` can catch the value itself.
+pub fn throw(value: Variant, mark: Mark) -> Result<Backtrace, Backtrace> {
+    let message = value.represent(Some(mark.clone()))?;
+    Ok(Backtrace::with_payload(
+        Log::error(message, Some(mark)),
+        Arc::new(value),
+    ))
+}
+
+/// Get the value carried by an error made with [`throw`].
+pub fn thrown_value(backtrace: &Backtrace) -> Option<Variant> {
+    backtrace.payload()?.downcast_ref::<Variant>().cloned()
+}
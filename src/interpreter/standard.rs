@@ -1,30 +1,98 @@
+pub mod abs_fn;
+pub mod acos_fn;
 pub mod add_fn;
+pub mod and_fn;
+pub mod asin_fn;
 pub mod assert_fn;
+pub mod atan_fn;
 pub mod break_fn;
+pub mod catch_fn;
+pub mod ceil_fn;
 pub mod closure_fn;
 pub mod console_fn;
 pub mod continue_fn;
+pub mod cos_fn;
 pub mod div_fn;
 pub mod duplicate_fn;
+pub mod elif_fn;
+pub mod else_fn;
 pub mod eq_fn;
+pub mod export_fn;
+pub mod floor_fn;
+pub mod for_fn;
 pub mod g_fn;
 pub mod ge_fn;
+pub mod if_fn;
 pub mod import_fn;
+pub mod json_parse_fn;
+pub mod json_stringify_fn;
 pub mod l_fn;
 pub mod le_fn;
+pub mod list_all_fn;
+pub mod list_any_fn;
+pub mod list_concat_fn;
+pub mod list_contains_fn;
+pub mod list_filter_fn;
+pub mod list_find_fn;
 pub mod list_fn;
 pub mod list_get_fn;
+pub mod list_index_of_fn;
+pub mod list_insert_fn;
 pub mod list_length_fn;
+pub mod list_map_fn;
 pub mod list_pop_fn;
 pub mod list_push_fn;
+pub mod list_reduce_fn;
+pub mod list_remove_fn;
+pub mod list_reverse_fn;
+pub mod list_set_fn;
+pub mod list_slice_fn;
+pub mod list_sort_fn;
+pub mod list_zip_fn;
+pub mod log_fn;
+pub mod max_fn;
+pub mod min_fn;
+pub mod mod_fn;
 pub mod mul_fn;
+pub mod not_fn;
+pub mod or_fn;
 pub mod parameter_fn;
+pub mod pow_fn;
 pub mod print_fn;
 pub mod println_fn;
 pub mod return_fn;
+pub mod round_fn;
 pub mod set_fn;
+pub mod sin_fn;
+pub mod sqrt_fn;
+pub mod string_chars_fn;
+pub mod string_ends_with_fn;
+pub mod string_find_fn;
+pub mod string_join_fn;
+pub mod string_length_fn;
+pub mod string_lower_fn;
+pub mod string_replace_fn;
+pub mod string_slice_fn;
+pub mod string_split_fn;
+pub mod string_starts_with_fn;
+pub mod string_trim_fn;
+pub mod string_upper_fn;
 pub mod sub_fn;
+pub mod table_extend_fn;
 pub mod table_fn;
+pub mod table_get_fn;
+pub mod table_has_fn;
+pub mod table_keys_fn;
+pub mod table_length_fn;
+pub mod table_merge_fn;
+pub mod table_prototype_fn;
+pub mod table_remove_fn;
+pub mod table_set_fn;
+pub mod table_set_prototype_fn;
+pub mod table_values_fn;
+pub mod tan_fn;
+pub mod throw_fn;
+pub mod try_fn;
 pub mod var_fn;
 pub mod when_fn;
 pub mod while_fn;
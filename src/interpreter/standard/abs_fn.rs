@@ -0,0 +1,29 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::float::Float;
+use crate::interpreter::variant::int::Int;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+
+pub fn abs_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let value = context.resolve_variant(&body[0])?;
+    if let Variant::INT(int) = value {
+        let int: i64 = int.into();
+        match int.checked_abs() {
+            Some(result) => return Ok(Signal::COMPLETE(Variant::INT(Int::from(result)))),
+            None => {
+                raise_error!(
+                    Some(body[0].mark.clone()),
+                    "Absolute value of `{}` overflows.",
+                    int
+                );
+            }
+        }
+    }
+    let number = Context::as_number(&value, &body[0])?;
+    Ok(Signal::COMPLETE(Variant::FLOAT(Float::from(number.abs()))))
+}
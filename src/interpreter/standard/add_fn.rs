@@ -10,7 +10,7 @@ pub fn add_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Sign
     let mut variant = context.resolve_variant(&body[0])?;
     for atom in body.iter().skip(1) {
         let rhs = context.resolve_variant(atom)?;
-        variant = variant.add(&rhs, Some(atom.mark.clone()))?;
+        variant = variant.add(&rhs, Some(atom.mark.clone()), context)?;
     }
     Ok(Signal::COMPLETE(variant))
 }
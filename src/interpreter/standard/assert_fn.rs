@@ -11,12 +11,11 @@ pub fn assert_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<S
     assert_atoms_count!(body, 2);
     let success = context.resolve_boolean(&body[0])?;
     if !success.is_true() {
+        let message = context.resolve_variant(&body[1])?;
         raise_error!(
             Some(body[0].mark.clone()),
             "{}",
-            context
-                .resolve_variant(&body[1])?
-                .represent(Some(body[1].mark.clone()))?
+            message.represent_in(Some(body[1].mark.clone()), context)?
         );
     }
     Ok(Signal::COMPLETE(Variant::NULL(Null())))
@@ -0,0 +1,13 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::float::Float;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn atan_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let number = context.resolve_number(&body[0])?;
+    Ok(Signal::COMPLETE(Variant::FLOAT(Float::from(number.atan()))))
+}
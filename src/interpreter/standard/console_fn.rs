@@ -16,14 +16,16 @@ pub fn console_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<
         let statement = atom_as_statement!(atom);
         let console_head = atom_as_identifier!(&statement[0]);
         let console_args = &statement[1..];
+        context
+            .capabilities
+            .check_console(console_head, Some(statement[0].mark.clone()))?;
 
         let mut console_statement = Command::new(console_head);
         for arg in console_args {
+            let value = context.resolve_variant(arg)?;
             console_statement.arg(format!(
                 "\"{}\"",
-                context
-                    .resolve_variant(arg)?
-                    .represent(Some(arg.mark.clone()))?,
+                value.represent_in(Some(arg.mark.clone()), context)?,
             ));
         }
 
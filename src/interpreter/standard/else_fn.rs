@@ -0,0 +1,12 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+
+pub fn else_fn(_context: &mut Context, head: &Atom, _body: &[Atom]) -> Result<Signal, Backtrace> {
+    raise_error!(
+        Some(head.mark.clone()),
+        "'else' is only allowed as a clause of 'if'."
+    );
+}
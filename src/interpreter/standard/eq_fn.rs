@@ -13,7 +13,7 @@ pub fn eq_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signa
     for atom in body.iter().skip(1) {
         let rhs = context.resolve_variant(atom)?;
 
-        if !variant.eq(&rhs, Some(atom.mark.clone()))? {
+        if !variant.eq(&rhs, Some(atom.mark.clone()), context)? {
             return Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(false))));
         }
     }
@@ -0,0 +1,17 @@
+use crate::assert_atoms_count_min;
+use crate::atom_as_identifier;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::null::Null;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn export_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    for atom in body.iter() {
+        let identifier = atom_as_identifier!(atom);
+        context.export(identifier.clone(), atom.mark.clone())?;
+    }
+    Ok(Signal::COMPLETE(Variant::NULL(Null())))
+}
@@ -0,0 +1,127 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::null::Null;
+use crate::interpreter::variant::represent::Represent;
+use crate::interpreter::variant::strand::Strand;
+use crate::interpreter::variant::table::Table;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::{Atom, AtomValue};
+use crate::{assert_atoms_count_min, atom_as_identifier, raise_error};
+
+const KEY_STR: &str = "key";
+const VALUE_STR: &str = "value";
+
+/// Run the commands of the loop with the variables bound, returning the signal that ends the loop.
+fn run_iteration(
+    context: &mut Context,
+    commands: &[Atom],
+    table: Table,
+) -> Result<Option<Signal>, Backtrace> {
+    let signal = context.run_statements(commands, table)?;
+    match signal {
+        Signal::BREAK(_) => Ok(Some(Signal::COMPLETE(Variant::NULL(Null::new())))),
+        Signal::RETURN(_, _) => Ok(Some(signal)),
+        Signal::CONTINUE(_) | Signal::COMPLETE(_) => Ok(None),
+    }
+}
+
+pub fn for_fn(context: &mut Context, head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 2);
+    let key_identifier = atom_as_identifier!(&body[0]);
+
+    // The atoms on the `for` line are the identifiers followed by the iterable. When the line
+    // only names the variable, the iterable is the first indented statement.
+    let line_count = body
+        .iter()
+        .position(|atom| matches!(atom.value, AtomValue::STATEMENT(_)))
+        .unwrap_or(body.len());
+    let (value_identifier, iterable_index) = match line_count {
+        1 | 2 => (None, 1),
+        3 => (Some(atom_as_identifier!(&body[1])), 2),
+        _ => {
+            raise_error!(
+                Some(body[3].mark.clone()),
+                "Expecting at most two identifiers followed by one iterable on the line of 'for'."
+            );
+        }
+    };
+    let iterable_atom = &body[iterable_index];
+    let commands = &body[iterable_index + 1..];
+    let mark = Some(iterable_atom.mark.clone());
+
+    let iterable = context.resolve_variant(iterable_atom)?;
+    match iterable {
+        Variant::LIST(list) => {
+            if let Some(identifier) = value_identifier {
+                raise_error!(
+                    Some(body[1].mark.clone()),
+                    "Unexpected identifier '{}', only a table binds two variables.",
+                    identifier
+                );
+            }
+            for element in list.to_vec(mark.clone())? {
+                let mut table = Table::default();
+                table.insert(key_identifier.clone(), element, mark.clone())?;
+                if let Some(signal) = run_iteration(context, commands, table)? {
+                    return Ok(signal);
+                }
+            }
+        }
+        Variant::TABLE(iterable_table) => {
+            for (key, value) in iterable_table.entries(mark.clone())? {
+                let key = Variant::STRAND(Strand::from(key));
+                let mut table = Table::default();
+                match value_identifier {
+                    Some(value_identifier) => {
+                        table.insert(key_identifier.clone(), key, mark.clone())?;
+                        table.insert(value_identifier.clone(), value, mark.clone())?;
+                    }
+                    None => {
+                        let pair = Table::from(vec![
+                            (String::from(KEY_STR), key),
+                            (String::from(VALUE_STR), value),
+                        ]);
+                        table.insert(key_identifier.clone(), Variant::TABLE(pair), mark.clone())?;
+                    }
+                }
+                if let Some(signal) = run_iteration(context, commands, table)? {
+                    return Ok(signal);
+                }
+            }
+        }
+        Variant::CLOSURE(mut closure) => {
+            if let Some(identifier) = value_identifier {
+                raise_error!(
+                    Some(body[1].mark.clone()),
+                    "Unexpected identifier '{}', only a table binds two variables.",
+                    identifier
+                );
+            }
+            loop {
+                let element = match closure.call_with(context, Vec::new())? {
+                    Signal::COMPLETE(value) => value,
+                    _ => {
+                        raise_error!(mark.clone(), "Unexpected control command from iterator.");
+                    }
+                };
+                if let Variant::NULL(_) = element {
+                    break;
+                }
+                let mut table = Table::default();
+                table.insert(key_identifier.clone(), element, mark.clone())?;
+                if let Some(signal) = run_iteration(context, commands, table)? {
+                    return Ok(signal);
+                }
+            }
+        }
+        _ => {
+            raise_error!(
+                Some(head.mark.clone()),
+                "`{}` is not iterable.",
+                iterable.represent_in(mark.clone(), context)?
+            );
+        }
+    }
+    Ok(Signal::COMPLETE(Variant::NULL(Null::new())))
+}
@@ -0,0 +1,69 @@
+use crate::assert_atoms_count_min;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::null::Null;
+use crate::interpreter::variant::table::Table;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::{as_clause, Atom, ELIF_STR, ELSE_STR};
+use crate::raise_error;
+
+pub fn if_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+
+    // Collect the clauses, each is an optional condition followed by its commands.
+    let mut clauses: Vec<(Option<&Atom>, &[Atom])> = Vec::new();
+    let split = body
+        .iter()
+        .skip(1)
+        .position(|atom| as_clause(atom).is_some())
+        .map_or(body.len(), |position| position + 1);
+    clauses.push((Some(&body[0]), &body[1..split]));
+
+    let mut is_else_found = false;
+    for atom in body[split..].iter() {
+        let (keyword, clause) = match as_clause(atom) {
+            Some(clause) => clause,
+            None => {
+                raise_error!(
+                    Some(atom.mark.clone()),
+                    "Expecting '{}' or '{}' clause after the clauses of 'if'.",
+                    ELIF_STR,
+                    ELSE_STR
+                );
+            }
+        };
+        if is_else_found {
+            raise_error!(
+                Some(atom.mark.clone()),
+                "'{}' must be the last clause of 'if'.",
+                ELSE_STR
+            );
+        }
+        if keyword == ELSE_STR {
+            is_else_found = true;
+            clauses.push((None, clause));
+        } else {
+            if clause.is_empty() {
+                raise_error!(
+                    Some(atom.mark.clone()),
+                    "'{}' expects a condition.",
+                    ELIF_STR
+                );
+            }
+            clauses.push((Some(&clause[0]), &clause[1..]));
+        }
+    }
+
+    for (condition, commands) in clauses {
+        let is_chosen = match condition {
+            Some(condition) => context.resolve_boolean(condition)?.is_true(),
+            None => true,
+        };
+        if is_chosen {
+            return context.run_block(commands, Table::default());
+        }
+    }
+
+    Ok(Signal::COMPLETE(Variant::NULL(Null::new())))
+}
@@ -3,10 +3,41 @@ use crate::interpreter::context::Context;
 use crate::interpreter::resource::ResourcePath;
 use crate::interpreter::signal::Signal;
 use crate::parser::atom::Atom;
-use crate::{assert_atoms_count, atom_as_identifier};
+use crate::{
+    assert_atoms_count_max, assert_atoms_count_min, atom_as_identifier, context_get_current_scope,
+    raise_error,
+};
 
-pub fn import_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
-    assert_atoms_count!(body, 1);
+pub const AS_STR: &str = "as";
+
+pub fn import_fn(context: &mut Context, head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    assert_atoms_count_max!(body, 3);
     let identifier = atom_as_identifier!(&body[0]);
-    context.run_resource(ResourcePath::try_from(identifier.clone())?)
+    let name = match body.len() {
+        1 => None,
+        3 if atom_as_identifier!(&body[1]) == AS_STR => Some(atom_as_identifier!(&body[2])),
+        _ => {
+            raise_error!(
+                Some(body[1].mark.clone()),
+                "Expecting `{} <name>` after the path.",
+                AS_STR
+            );
+        }
+    };
+
+    let path = ResourcePath::try_from(identifier.clone())?;
+    let value = context.import_resource(path, head.mark.clone())?;
+    if let Some(name) = name {
+        let table = context_get_current_scope!(context);
+        let popped = table.insert(name.clone(), value.clone(), Some(body[2].mark.clone()))?;
+        if popped.is_some() {
+            raise_error!(
+                Some(body[2].mark.clone()),
+                "Redeclaration of variable '{}'.",
+                name
+            );
+        }
+    }
+    Ok(Signal::COMPLETE(value))
 }
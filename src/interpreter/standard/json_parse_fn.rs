@@ -0,0 +1,28 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::json::parse;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+
+pub fn json_parse_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let text = context.resolve_strand(&body[0])?;
+    match parse(text.as_str()) {
+        Ok(value) => Ok(Signal::COMPLETE(value)),
+        Err(error) => {
+            raise_error!(
+                Some(body[0].mark.clone()),
+                "Invalid JSON at line {}, column {}: {}",
+                error.line,
+                error.column,
+                error.message
+            );
+        }
+    }
+}
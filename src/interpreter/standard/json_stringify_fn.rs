@@ -0,0 +1,32 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::json::stringify;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::strand::Strand;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min, raise_error};
+
+pub fn json_stringify_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    assert_atoms_count_max!(body, 2);
+    let value = context.resolve_variant(&body[0])?;
+    let indent = if body.len() == 2 {
+        let indent: i64 = context.resolve_int(&body[1])?.into();
+        if indent < 0 {
+            raise_error!(
+                Some(body[1].mark.clone()),
+                "Indentation must be a non-negative integer."
+            );
+        }
+        Some(indent as usize)
+    } else {
+        None
+    };
+    let text = stringify(&value, indent, Some(body[0].mark.clone()))?;
+    Ok(Signal::COMPLETE(Variant::STRAND(Strand::from(text))))
+}
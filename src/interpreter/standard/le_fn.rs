@@ -12,7 +12,7 @@ pub fn le_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signa
     let mut variant = context.resolve_variant(&body[0])?;
     for atom in body.iter().skip(1) {
         let rhs = context.resolve_variant(atom)?;
-        if !variant.le(&rhs, Some(atom.mark.clone()))? {
+        if !variant.le(&rhs, Some(atom.mark.clone()), context)? {
             return Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(false))));
         }
         variant = rhs;
@@ -0,0 +1,13 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::standard::list_any_fn::find_callback_result;
+use crate::parser::atom::Atom;
+
+pub fn list_all_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    find_callback_result(context, body, false)
+}
@@ -0,0 +1,43 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::boolean::Boolean;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count, raise_error};
+
+/// Call the callback on each element of the list, returning `stop` at the first element the
+/// callback returns `stop` for, or its negation if there is none.
+pub fn find_callback_result(
+    context: &mut Context,
+    body: &[Atom],
+    stop: bool,
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let list = context.resolve_list(&body[0])?;
+    let mut callback = context.resolve_closure(&body[1])?;
+    for element in list.to_vec(Some(body[0].mark.clone()))? {
+        match callback.call_back(context, vec![element], body[1].mark.clone())? {
+            Variant::BOOL(boolean) => {
+                if boolean.is_true() == stop {
+                    return Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(stop))));
+                }
+            }
+            _ => {
+                raise_error!(
+                    Some(body[1].mark.clone()),
+                    "Callback must return a boolean."
+                );
+            }
+        }
+    }
+    Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(!stop))))
+}
+
+pub fn list_any_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    find_callback_result(context, body, true)
+}
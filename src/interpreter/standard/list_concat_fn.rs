@@ -0,0 +1,19 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::list::List;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn list_concat_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    let mut elements: Vec<Variant> = Vec::new();
+    for atom in body.iter() {
+        let list = context.resolve_list(atom)?;
+        elements.append(&mut list.to_vec(Some(atom.mark.clone()))?);
+    }
+    Ok(Signal::COMPLETE(Variant::LIST(List::from(elements))))
+}
@@ -0,0 +1,22 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::boolean::Boolean;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn list_contains_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let list = context.resolve_list(&body[0])?;
+    let element = context.resolve_variant(&body[1])?;
+    let index = list.index_of(&element, Some(body[1].mark.clone()), context)?;
+    Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(!matches!(
+        index,
+        Variant::NULL(_)
+    )))))
+}
@@ -0,0 +1,34 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::list::List;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count, raise_error};
+
+pub fn list_filter_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let list = context.resolve_list(&body[0])?;
+    let mut callback = context.resolve_closure(&body[1])?;
+    let mut filtered: Vec<Variant> = Vec::new();
+    for element in list.to_vec(Some(body[0].mark.clone()))? {
+        match callback.call_back(context, vec![element.clone()], body[1].mark.clone())? {
+            Variant::BOOL(boolean) => {
+                if boolean.is_true() {
+                    filtered.push(element);
+                }
+            }
+            _ => {
+                raise_error!(
+                    Some(body[1].mark.clone()),
+                    "Callback must return a boolean."
+                );
+            }
+        }
+    }
+    Ok(Signal::COMPLETE(Variant::LIST(List::from(filtered))))
+}
@@ -0,0 +1,33 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::null::Null;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count, raise_error};
+
+pub fn list_find_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let list = context.resolve_list(&body[0])?;
+    let mut callback = context.resolve_closure(&body[1])?;
+    for element in list.to_vec(Some(body[0].mark.clone()))? {
+        match callback.call_back(context, vec![element.clone()], body[1].mark.clone())? {
+            Variant::BOOL(boolean) => {
+                if boolean.is_true() {
+                    return Ok(Signal::COMPLETE(element));
+                }
+            }
+            _ => {
+                raise_error!(
+                    Some(body[1].mark.clone()),
+                    "Callback must return a boolean."
+                );
+            }
+        }
+    }
+    Ok(Signal::COMPLETE(Variant::NULL(Null())))
+}
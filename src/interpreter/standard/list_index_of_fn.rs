@@ -0,0 +1,20 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+
+pub fn list_index_of_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let list = context.resolve_list(&body[0])?;
+    let element = context.resolve_variant(&body[1])?;
+    Ok(Signal::COMPLETE(list.index_of(
+        &element,
+        Some(body[1].mark.clone()),
+        context,
+    )?))
+}
@@ -0,0 +1,19 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn list_insert_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 3);
+    let mut list = context.resolve_list(&body[0])?;
+    let index = context.resolve_int(&body[1])?;
+    let element = context.resolve_variant(&body[2])?;
+    list.insert(index, element, Some(body[1].mark.clone()))?;
+    Ok(Signal::COMPLETE(Variant::LIST(list)))
+}
@@ -0,0 +1,22 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::list::List;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn list_map_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let list = context.resolve_list(&body[0])?;
+    let mut callback = context.resolve_closure(&body[1])?;
+    let mut mapped: Vec<Variant> = Vec::new();
+    for element in list.to_vec(Some(body[0].mark.clone()))? {
+        mapped.push(callback.call_back(context, vec![element], body[1].mark.clone())?);
+    }
+    Ok(Signal::COMPLETE(Variant::LIST(List::from(mapped))))
+}
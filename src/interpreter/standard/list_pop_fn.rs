@@ -1,4 +1,4 @@
-use crate::assert_atoms_count_min;
+use crate::assert_atoms_count;
 use crate::backtrace::Backtrace;
 use crate::interpreter::context::Context;
 use crate::interpreter::signal::Signal;
@@ -9,7 +9,7 @@ pub fn list_pop_fn(
     _head: &Atom,
     body: &[Atom],
 ) -> Result<Signal, Backtrace> {
-    assert_atoms_count_min!(body, 2);
+    assert_atoms_count!(body, 1);
     let mut list = context.resolve_list(&body[0])?;
     let value = list.pop(Some(body[0].mark.clone()))?;
     Ok(Signal::COMPLETE(value))
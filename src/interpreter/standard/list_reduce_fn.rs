@@ -0,0 +1,37 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min, raise_error};
+
+pub fn list_reduce_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 2);
+    assert_atoms_count_max!(body, 3);
+    let list = context.resolve_list(&body[0])?;
+    let mut callback = context.resolve_closure(&body[1])?;
+    let mut elements = list.to_vec(Some(body[0].mark.clone()))?.into_iter();
+
+    // Without an initial value, the first element starts the reduction.
+    let mut accumulator = if body.len() == 3 {
+        context.resolve_variant(&body[2])?
+    } else {
+        match elements.next() {
+            Some(element) => element,
+            None => {
+                raise_error!(
+                    Some(body[0].mark.clone()),
+                    "Reducing an empty list requires an initial value."
+                );
+            }
+        }
+    };
+    for element in elements {
+        accumulator =
+            callback.call_back(context, vec![accumulator, element], body[1].mark.clone())?;
+    }
+    Ok(Signal::COMPLETE(accumulator))
+}
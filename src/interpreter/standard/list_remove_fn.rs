@@ -0,0 +1,17 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+
+pub fn list_remove_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let mut list = context.resolve_list(&body[0])?;
+    let index = context.resolve_int(&body[1])?;
+    let element = list.remove(index, Some(body[1].mark.clone()))?;
+    Ok(Signal::COMPLETE(element))
+}
@@ -0,0 +1,17 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn list_reverse_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let mut list = context.resolve_list(&body[0])?;
+    list.reverse(Some(body[0].mark.clone()))?;
+    Ok(Signal::COMPLETE(Variant::LIST(list)))
+}
@@ -0,0 +1,23 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min};
+
+pub fn list_sort_fn(
+    context: &mut Context,
+    head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    assert_atoms_count_max!(body, 2);
+    let mut list = context.resolve_list(&body[0])?;
+    if body.len() == 1 {
+        list.sort(context, None, head.mark.clone())?;
+    } else {
+        let comparator = context.resolve_closure(&body[1])?;
+        list.sort(context, Some(comparator), body[1].mark.clone())?;
+    }
+    Ok(Signal::COMPLETE(Variant::LIST(list)))
+}
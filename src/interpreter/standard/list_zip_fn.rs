@@ -0,0 +1,34 @@
+use crate::assert_atoms_count_min;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::list::List;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn list_zip_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    let mut lists: Vec<Vec<Variant>> = Vec::new();
+    for atom in body.iter() {
+        let list = context.resolve_list(atom)?;
+        lists.push(list.to_vec(Some(atom.mark.clone()))?);
+    }
+
+    // Zipping stops at the shortest list.
+    let length = lists.iter().map(|list| list.len()).min().unwrap_or(0);
+    let zipped = (0..length)
+        .map(|i| {
+            Variant::LIST(List::from(
+                lists
+                    .iter()
+                    .map(|list| list[i].clone())
+                    .collect::<Vec<Variant>>(),
+            ))
+        })
+        .collect::<Vec<Variant>>();
+    Ok(Signal::COMPLETE(Variant::LIST(List::from(zipped))))
+}
@@ -0,0 +1,34 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::float::Float;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min, raise_error};
+
+pub fn log_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    assert_atoms_count_max!(body, 2);
+    let number = context.resolve_number(&body[0])?;
+    if number <= 0.0 {
+        raise_error!(
+            Some(body[0].mark.clone()),
+            "Logarithm of `{}` is undefined.",
+            number
+        );
+    }
+    if body.len() == 1 {
+        return Ok(Signal::COMPLETE(Variant::FLOAT(Float::from(number.ln()))));
+    }
+    let base = context.resolve_number(&body[1])?;
+    if base <= 0.0 || base == 1.0 {
+        raise_error!(
+            Some(body[1].mark.clone()),
+            "Logarithm with base `{}` is undefined.",
+            base
+        );
+    }
+    Ok(Signal::COMPLETE(Variant::FLOAT(Float::from(
+        number.log(base),
+    ))))
+}
@@ -0,0 +1,23 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::variant_ops::VariantG;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_min, raise_error};
+
+pub fn max_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    let mut largest: Option<Variant> = None;
+    for atom in body.iter() {
+        let value = context.resolve_variant(atom)?;
+        if !matches!(value, Variant::INT(_) | Variant::FLOAT(_)) {
+            raise_error!(Some(atom.mark.clone()), "Variant given is not a number.");
+        }
+        largest = match largest {
+            Some(current) if !value.g(&current, Some(atom.mark.clone()), context)? => Some(current),
+            _ => Some(value),
+        };
+    }
+    Ok(Signal::COMPLETE(largest.unwrap()))
+}
@@ -0,0 +1,23 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::variant_ops::VariantL;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_min, raise_error};
+
+pub fn min_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    let mut smallest: Option<Variant> = None;
+    for atom in body.iter() {
+        let value = context.resolve_variant(atom)?;
+        if !matches!(value, Variant::INT(_) | Variant::FLOAT(_)) {
+            raise_error!(Some(atom.mark.clone()), "Variant given is not a number.");
+        }
+        smallest = match smallest {
+            Some(current) if !value.l(&current, Some(atom.mark.clone()), context)? => Some(current),
+            _ => Some(value),
+        };
+    }
+    Ok(Signal::COMPLETE(smallest.unwrap()))
+}
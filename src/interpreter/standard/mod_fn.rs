@@ -0,0 +1,46 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::float::Float;
+use crate::interpreter::variant::int::Int;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+
+pub fn mod_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let lhs = context.resolve_variant(&body[0])?;
+    let rhs = context.resolve_variant(&body[1])?;
+
+    // The remainder takes the sign of the divisor, so `mod -1 3` is `2`.
+    if let (Variant::INT(lhs), Variant::INT(rhs)) = (&lhs, &rhs) {
+        let (lhs, rhs): (i64, i64) = ((*lhs).into(), (*rhs).into());
+        if rhs == 0 {
+            raise_error!(Some(body[1].mark.clone()), "Modulo by zero is undefined.");
+        }
+        let mut remainder = lhs.wrapping_rem(rhs);
+        if remainder != 0 && (remainder < 0) != (rhs < 0) {
+            remainder += rhs;
+        }
+        return Ok(Signal::COMPLETE(Variant::INT(Int::from(remainder))));
+    }
+
+    let lhs = Context::as_number(&lhs, &body[0])?;
+    let rhs = Context::as_number(&rhs, &body[1])?;
+    if rhs == 0.0 {
+        raise_error!(Some(body[1].mark.clone()), "Modulo by zero is undefined.");
+    }
+    if lhs.is_infinite() {
+        raise_error!(
+            Some(body[0].mark.clone()),
+            "Modulo of `{}` is undefined.",
+            lhs
+        );
+    }
+    let mut remainder = lhs % rhs;
+    if remainder != 0.0 && (remainder < 0.0) != (rhs < 0.0) {
+        remainder += rhs;
+    }
+    Ok(Signal::COMPLETE(Variant::FLOAT(Float::from(remainder))))
+}
@@ -7,10 +7,10 @@ use crate::parser::atom::Atom;
 
 pub fn mul_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
     assert_atoms_count_min!(body, 2);
-    let mut variant = context.resolve_variant(&body[1])?;
+    let mut variant = context.resolve_variant(&body[0])?;
     for atom in body.iter().skip(1) {
         let rhs = context.resolve_variant(atom)?;
-        variant = variant.mul(&rhs, Some(atom.mark.clone()))?;
+        variant = variant.mul(&rhs, Some(atom.mark.clone()), context)?;
     }
     Ok(Signal::COMPLETE(variant))
 }
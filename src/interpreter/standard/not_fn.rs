@@ -0,0 +1,15 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::boolean::Boolean;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn not_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let boolean = context.resolve_boolean(&body[0])?;
+    Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(
+        !boolean.is_true(),
+    ))))
+}
@@ -0,0 +1,18 @@
+use crate::assert_atoms_count_min;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::boolean::Boolean;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn or_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 2);
+    for atom in body.iter() {
+        let boolean = context.resolve_boolean(atom)?;
+        if boolean.is_true() {
+            return Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(true))));
+        }
+    }
+    Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(false))))
+}
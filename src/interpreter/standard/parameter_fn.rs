@@ -1,48 +1,209 @@
 use crate::backtrace::Backtrace;
 use crate::interpreter::context::Context;
 use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::list::List;
 use crate::interpreter::variant::null::Null;
 use crate::interpreter::variant::Variant;
-use crate::parser::atom::Atom;
-use crate::{atom_as_identifier, context_get_current_scope, raise_error};
+use crate::log::Log;
+use crate::mark::Mark;
+use crate::parser::atom::{Atom, AtomValue};
+use crate::{context_get_current_scope, raise_error};
+use std::mem;
+
+/// Prefix of the parameter that collects the rest of the positional arguments into a list.
+pub const REST_PREFIX: &str = "...";
+
+struct Parameter<'a> {
+    name: &'a String,
+    default: Option<&'a Atom>,
+    mark: &'a Mark,
+}
+
+/// Raise an error on the arguments at the call site, noting where the parameters are declared.
+fn raise_arity_error<T>(
+    message: String,
+    call_mark: Option<Mark>,
+    head: &Atom,
+) -> Result<T, Backtrace> {
+    match call_mark {
+        Some(call_mark) => Err(
+            Backtrace::new(Log::error(message, Some(call_mark))).with_note(Log::note(
+                String::from("The parameters are declared here."),
+                Some(head.mark.clone()),
+            )),
+        ),
+        None => Err(Backtrace::new(Log::error(message, Some(head.mark.clone())))),
+    }
+}
 
 pub fn parameter_fn(
     context: &mut Context,
     head: &Atom,
     body: &[Atom],
 ) -> Result<Signal, Backtrace> {
-    let table = context_get_current_scope!(context);
-    for atom in body.iter().rev() {
-        let identifier = atom_as_identifier!(atom);
-        let argument = context.slots.pop();
-        match argument {
-            Option::None => {
-                raise_error!(
-                    Some(atom.mark.clone()),
-                    "Arguments supplied is insufficient for '{}'",
-                    identifier
-                );
-            }
-            Option::Some(variant) => {
-                let variant = table.insert(identifier.clone(), variant, Some(head.mark.clone()))?;
-                if variant.is_some() {
+    // Collect the declaration, `<name>`, `<name> {default}` as a statement, and `...<name>`.
+    let mut parameters: Vec<Parameter<'_>> = Vec::new();
+    let mut rest: Option<(&String, &Mark)> = None;
+    for atom in body.iter() {
+        if rest.is_some() {
+            raise_error!(
+                Some(atom.mark.clone()),
+                "The rest parameter must be the last parameter."
+            );
+        }
+        let parameter = match atom.value {
+            AtomValue::IDENTIFIER(ref identifier) if identifier.starts_with(REST_PREFIX) => {
+                if identifier.len() == REST_PREFIX.len() {
+                    raise_error!(Some(atom.mark.clone()), "The rest parameter needs a name.");
+                }
+                let name = &identifier[REST_PREFIX.len()..];
+                if parameters.iter().any(|x| x.name == name) {
                     raise_error!(
                         Some(atom.mark.clone()),
-                        "'{}' has already been defined.",
-                        identifier
+                        "Parameter '{}' is declared more than once.",
+                        name
                     );
                 }
+                rest = Some((identifier, &atom.mark));
+                continue;
+            }
+            AtomValue::IDENTIFIER(ref identifier) => Parameter {
+                name: identifier,
+                default: None,
+                mark: &atom.mark,
+            },
+            AtomValue::STATEMENT(ref statement) if statement.len() == 2 => match statement[0].value
+            {
+                AtomValue::IDENTIFIER(ref identifier) => Parameter {
+                    name: identifier,
+                    default: Some(&statement[1]),
+                    mark: &statement[0].mark,
+                },
+                _ => {
+                    raise_error!(Some(statement[0].mark.clone()), "Expecting an identifier.");
+                }
+            },
+            _ => {
+                raise_error!(
+                    Some(atom.mark.clone()),
+                    "Expecting a parameter, which is `<name>`, `<name> {{default}}` or `{}<name>`.",
+                    REST_PREFIX
+                );
             }
+        };
+        if parameter.default.is_none() && parameters.iter().any(|x| x.default.is_some()) {
+            raise_error!(
+                Some(atom.mark.clone()),
+                "Parameter '{}' without default cannot follow parameters with defaults.",
+                parameter.name
+            );
         }
+        if parameters.iter().any(|x| x.name == parameter.name) {
+            raise_error!(
+                Some(atom.mark.clone()),
+                "Parameter '{}' is declared more than once.",
+                parameter.name
+            );
+        }
+        parameters.push(parameter);
     }
 
-    if context.slots.len() != 0 {
-        raise_error!(
-            Some(head.mark.clone()),
-            "Excess arguments are passed, remaining {} argument(s).",
-            context.slots.len()
+    // Check the arguments against the declaration before binding any of them.
+    let mut slots = mem::take(&mut context.slots);
+    let keywords = mem::take(&mut context.keywords);
+    let call_mark = context.call_mark.clone();
+    if rest.is_none() && slots.len() > parameters.len() {
+        let required = parameters.iter().filter(|x| x.default.is_none()).count();
+        let expected = if required == parameters.len() {
+            format!("{}", required)
+        } else {
+            format!("{} to {}", required, parameters.len())
+        };
+        return raise_arity_error(
+            format!(
+                "Expecting {} argument(s), but {} are given.",
+                expected,
+                slots.len()
+            ),
+            call_mark,
+            head,
         );
     }
+    for (keyword, _) in keywords.iter() {
+        match parameters.iter().position(|x| x.name == keyword) {
+            Some(i) if i < slots.len() => {
+                return raise_arity_error(
+                    format!(
+                        "Argument '{}' is given both by position and by keyword.",
+                        keyword
+                    ),
+                    call_mark,
+                    head,
+                );
+            }
+            Some(_) => {}
+            None => {
+                return raise_arity_error(
+                    format!("Unknown keyword argument '{}'.", keyword),
+                    call_mark,
+                    head,
+                );
+            }
+        }
+    }
+    for (i, parameter) in parameters.iter().enumerate() {
+        let is_given = i < slots.len() || keywords.iter().any(|(name, _)| name == parameter.name);
+        if !is_given && parameter.default.is_none() {
+            return raise_arity_error(
+                format!("Argument '{}' is not given.", parameter.name),
+                call_mark,
+                head,
+            );
+        }
+    }
+
+    // Bind in declaration order, so defaults can refer to earlier parameters.
+    let remaining = if slots.len() > parameters.len() {
+        slots.split_off(parameters.len())
+    } else {
+        Vec::new()
+    };
+    let mut slots = slots.into_iter();
+    let mut keywords = keywords;
+    for parameter in parameters.iter() {
+        let value = match slots.next() {
+            Some(value) => value,
+            None => match keywords.iter().position(|(name, _)| name == parameter.name) {
+                Some(i) => keywords.swap_remove(i).1,
+                None => context.resolve_variant(parameter.default.unwrap())?,
+            },
+        };
+        let table = context_get_current_scope!(context);
+        if table
+            .insert(parameter.name.clone(), value, Some(head.mark.clone()))?
+            .is_some()
+        {
+            raise_error!(
+                Some(parameter.mark.clone()),
+                "'{}' has already been defined.",
+                parameter.name
+            );
+        }
+    }
+    if let Some((name, mark)) = rest {
+        let name = String::from(&name[REST_PREFIX.len()..]);
+        let table = context_get_current_scope!(context);
+        if table
+            .insert(
+                name.clone(),
+                Variant::LIST(List::from(remaining)),
+                Some(head.mark.clone()),
+            )?
+            .is_some()
+        {
+            raise_error!(Some(mark.clone()), "'{}' has already been defined.", name);
+        }
+    }
 
     Ok(Signal::COMPLETE(Variant::NULL(Null())))
 }
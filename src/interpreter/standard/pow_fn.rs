@@ -0,0 +1,49 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::float::Float;
+use crate::interpreter::variant::int::Int;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+
+pub fn pow_fn(context: &mut Context, head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let base = context.resolve_variant(&body[0])?;
+    let exponent = context.resolve_variant(&body[1])?;
+
+    // Integers raised to a non-negative integer stay integers.
+    if let (Variant::INT(base), Variant::INT(exponent)) = (&base, &exponent) {
+        let (base, exponent): (i64, i64) = ((*base).into(), (*exponent).into());
+        if exponent >= 0 {
+            let result = u32::try_from(exponent)
+                .ok()
+                .and_then(|exponent| base.checked_pow(exponent));
+            match result {
+                Some(result) => return Ok(Signal::COMPLETE(Variant::INT(Int::from(result)))),
+                None => {
+                    raise_error!(
+                        Some(head.mark.clone()),
+                        "Raising `{}` to the power of `{}` overflows.",
+                        base,
+                        exponent
+                    );
+                }
+            }
+        }
+    }
+
+    let base = Context::as_number(&base, &body[0])?;
+    let exponent = Context::as_number(&exponent, &body[1])?;
+    let result = base.powf(exponent);
+    if result.is_nan() || (result.is_infinite() && base.is_finite() && exponent.is_finite()) {
+        raise_error!(
+            Some(head.mark.clone()),
+            "Raising `{}` to the power of `{}` is undefined.",
+            base,
+            exponent
+        );
+    }
+    Ok(Signal::COMPLETE(Variant::FLOAT(Float::from(result))))
+}
@@ -9,7 +9,7 @@ use crate::parser::atom::Atom;
 pub fn print_fn(context: &mut Context, head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
     for atom in body.iter() {
         let value = context.resolve_variant(atom)?;
-        print!("{}", value.represent(Some(head.mark.clone()))?);
+        print!("{}", value.represent_in(Some(head.mark.clone()), context)?);
     }
     Ok(Signal::COMPLETE(Variant::NULL(Null())))
 }
@@ -0,0 +1,22 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::int::Int;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+
+pub fn round_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let number = context.resolve_number(&body[0])?;
+    let result = number.round();
+    if !(result >= i64::MIN as f64 && result < i64::MAX as f64) {
+        raise_error!(
+            Some(body[0].mark.clone()),
+            "`{:?}` is out of the integer range.",
+            number
+        );
+    }
+    Ok(Signal::COMPLETE(Variant::INT(Int::from(result as i64))))
+}
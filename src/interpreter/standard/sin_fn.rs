@@ -0,0 +1,20 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::float::Float;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count, raise_error};
+
+pub fn sin_fn(context: &mut Context, _head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let number = context.resolve_number(&body[0])?;
+    if number.is_infinite() {
+        raise_error!(
+            Some(body[0].mark.clone()),
+            "Sine of `{}` is undefined.",
+            number
+        );
+    }
+    Ok(Signal::COMPLETE(Variant::FLOAT(Float::from(number.sin()))))
+}
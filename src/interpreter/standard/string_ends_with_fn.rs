@@ -0,0 +1,20 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::boolean::Boolean;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn string_ends_with_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let strand = context.resolve_strand(&body[0])?;
+    let suffix = context.resolve_strand(&body[1])?;
+    Ok(Signal::COMPLETE(Variant::BOOL(Boolean::from(
+        strand.as_str().ends_with(suffix.as_str()),
+    ))))
+}
@@ -0,0 +1,32 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::represent::Represent;
+use crate::interpreter::variant::strand::Strand;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min};
+
+pub fn string_join_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    assert_atoms_count_max!(body, 2);
+    let list = context.resolve_list(&body[0])?;
+    let separator = if body.len() == 2 {
+        context.resolve_strand(&body[1])?
+    } else {
+        Strand::from("")
+    };
+    let mark = Some(body[0].mark.clone());
+    let parts = list
+        .to_vec(mark.clone())?
+        .iter()
+        .map(|element| element.represent_in(mark.clone(), context))
+        .collect::<Result<Vec<String>, Backtrace>>()?;
+    Ok(Signal::COMPLETE(Variant::STRAND(Strand::from(
+        parts.join(separator.as_str()),
+    ))))
+}
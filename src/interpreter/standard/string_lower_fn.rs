@@ -0,0 +1,19 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::strand::Strand;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn string_lower_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let strand = context.resolve_strand(&body[0])?;
+    Ok(Signal::COMPLETE(Variant::STRAND(Strand::from(
+        strand.as_str().to_lowercase(),
+    ))))
+}
@@ -0,0 +1,26 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min};
+
+pub fn string_slice_fn(
+    context: &mut Context,
+    head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 2);
+    assert_atoms_count_max!(body, 3);
+    let strand = context.resolve_strand(&body[0])?;
+    let start = context.resolve_int(&body[1])?;
+    let end = if body.len() == 3 {
+        Some(context.resolve_int(&body[2])?)
+    } else {
+        None
+    };
+    Ok(Signal::COMPLETE(strand.slice(
+        start,
+        end,
+        Some(head.mark.clone()),
+    )?))
+}
@@ -0,0 +1,33 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::list::List;
+use crate::interpreter::variant::strand::Strand;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min, raise_error};
+
+pub fn string_split_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    assert_atoms_count_max!(body, 2);
+    let strand = context.resolve_strand(&body[0])?;
+    let parts: Vec<&str> = if body.len() == 2 {
+        let separator = context.resolve_strand(&body[1])?;
+        if separator.as_str().is_empty() {
+            raise_error!(Some(body[1].mark.clone()), "Separator cannot be empty.");
+        }
+        strand.as_str().split(separator.as_str()).collect()
+    } else {
+        strand.as_str().split_whitespace().collect()
+    };
+    Ok(Signal::COMPLETE(Variant::LIST(List::from(
+        parts
+            .into_iter()
+            .map(|part| Variant::STRAND(Strand::from(part)))
+            .collect::<Vec<Variant>>(),
+    ))))
+}
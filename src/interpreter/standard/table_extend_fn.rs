@@ -0,0 +1,18 @@
+use crate::assert_atoms_count_min;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::table::Table;
+use crate::parser::atom::Atom;
+
+pub fn table_extend_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+    let base = context.resolve_table(&body[0])?;
+    let table = Table::default();
+    table.set_prototype(Some(base), Some(body[0].mark.clone()))?;
+    context.run_statements(&body[1..], table)
+}
@@ -0,0 +1,27 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count_max, assert_atoms_count_min, raise_error};
+
+pub fn table_get_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 2);
+    assert_atoms_count_max!(body, 3);
+    let table = context.resolve_table(&body[0])?;
+    let key: String = context.resolve_strand(&body[1])?.into();
+    match table.get(&key, Some(body[1].mark.clone()))? {
+        Some(value) => Ok(Signal::COMPLETE(value)),
+        None if body.len() == 3 => Ok(Signal::COMPLETE(context.resolve_variant(&body[2])?)),
+        None => {
+            raise_error!(
+                Some(body[1].mark.clone()),
+                "Key '{}' is not found in the table.",
+                key
+            );
+        }
+    }
+}
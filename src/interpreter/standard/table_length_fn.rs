@@ -0,0 +1,15 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+
+pub fn table_length_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let table = context.resolve_table(&body[0])?;
+    Ok(Signal::COMPLETE(table.length(Some(body[0].mark.clone()))?))
+}
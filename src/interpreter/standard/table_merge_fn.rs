@@ -0,0 +1,22 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::table::Table;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn table_merge_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    // Later tables override the entries of the earlier ones.
+    let mut merged = Table::default();
+    for atom in body.iter() {
+        let table = context.resolve_table(atom)?;
+        for (key, value) in table.entries(Some(atom.mark.clone()))? {
+            merged.insert(key, value, Some(atom.mark.clone()))?;
+        }
+    }
+    Ok(Signal::COMPLETE(Variant::TABLE(merged)))
+}
@@ -0,0 +1,25 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::parser::atom::Atom;
+use crate::{assert_atoms_count, raise_error};
+
+pub fn table_remove_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let mut table = context.resolve_table(&body[0])?;
+    let key: String = context.resolve_strand(&body[1])?.into();
+    match table.remove(&key, Some(body[1].mark.clone()))? {
+        Some(value) => Ok(Signal::COMPLETE(value)),
+        None => {
+            raise_error!(
+                Some(body[1].mark.clone()),
+                "Key '{}' is not found in the table.",
+                key
+            );
+        }
+    }
+}
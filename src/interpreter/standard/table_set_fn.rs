@@ -0,0 +1,19 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+
+pub fn table_set_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 3);
+    let mut table = context.resolve_table(&body[0])?;
+    let key: String = context.resolve_strand(&body[1])?.into();
+    let value = context.resolve_variant(&body[2])?;
+    table.insert(key, value, Some(body[1].mark.clone()))?;
+    Ok(Signal::COMPLETE(Variant::TABLE(table)))
+}
@@ -0,0 +1,28 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::Atom;
+use crate::raise_error;
+
+pub fn table_set_prototype_fn(
+    context: &mut Context,
+    _head: &Atom,
+    body: &[Atom],
+) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 2);
+    let table = context.resolve_table(&body[0])?;
+    let prototype = match context.resolve_variant(&body[1])? {
+        Variant::TABLE(prototype) => Some(prototype),
+        Variant::NULL(_) => None,
+        _ => {
+            raise_error!(
+                Some(body[1].mark.clone()),
+                "Variant given is neither a table nor null."
+            );
+        }
+    };
+    table.set_prototype(prototype, Some(body[1].mark.clone()))?;
+    Ok(Signal::COMPLETE(Variant::TABLE(table)))
+}
@@ -0,0 +1,11 @@
+use crate::assert_atoms_count;
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::{throw, Signal};
+use crate::parser::atom::Atom;
+
+pub fn throw_fn(context: &mut Context, head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count!(body, 1);
+    let value = context.resolve_variant(&body[0])?;
+    Err(throw(value, head.mark.clone())?)
+}
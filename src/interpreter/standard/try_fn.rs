@@ -0,0 +1,97 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::{thrown_value, Signal};
+use crate::interpreter::variant::int::Int;
+use crate::interpreter::variant::null::Null;
+use crate::interpreter::variant::strand::Strand;
+use crate::interpreter::variant::table::Table;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::{Atom, AtomValue};
+use crate::{assert_atoms_count_min, atom_as_identifier, raise_error};
+
+pub const CATCH_STR: &str = "catch";
+
+/// Describe the error as a table for the `catch` clause.
+fn describe(backtrace: &Backtrace) -> Table {
+    let (name, row, column) = match backtrace.mark() {
+        Some(mark) => (
+            Variant::STRAND(Strand::from(mark.line.name.as_str())),
+            Variant::INT(Int::from((mark.line.row + 1) as i64)),
+            Variant::INT(Int::from((mark.column.start() + 1) as i64)),
+        ),
+        None => (
+            Variant::NULL(Null()),
+            Variant::NULL(Null()),
+            Variant::NULL(Null()),
+        ),
+    };
+    Table::from(vec![
+        (
+            String::from("message"),
+            Variant::STRAND(Strand::from(backtrace.message())),
+        ),
+        (
+            String::from("value"),
+            thrown_value(backtrace).unwrap_or(Variant::NULL(Null())),
+        ),
+        (String::from("name"), name),
+        (String::from("row"), row),
+        (String::from("column"), column),
+        (
+            String::from("backtrace"),
+            Variant::STRAND(Strand::from(backtrace.to_string())),
+        ),
+    ])
+}
+
+pub fn try_fn(context: &mut Context, head: &Atom, body: &[Atom]) -> Result<Signal, Backtrace> {
+    assert_atoms_count_min!(body, 1);
+
+    let catch = body.last().unwrap();
+    let catch_statement = match catch.value {
+        AtomValue::STATEMENT(ref statement) => match statement.first() {
+            Some(Atom {
+                value: AtomValue::IDENTIFIER(ref identifier),
+                ..
+            }) if identifier == CATCH_STR => statement,
+            _ => {
+                raise_error!(
+                    Some(head.mark.clone()),
+                    "Expecting '{}' clause as the last statement of 'try'.",
+                    CATCH_STR
+                );
+            }
+        },
+        _ => {
+            raise_error!(Some(catch.mark.clone()), "Expecting statement.");
+        }
+    };
+    if catch_statement.len() < 2 {
+        raise_error!(
+            Some(catch.mark.clone()),
+            "'{}' expects an identifier for the error.",
+            CATCH_STR
+        );
+    }
+    let identifier = atom_as_identifier!(&catch_statement[1]);
+
+    let scopes_count = context.scopes.len();
+    let slots_count = context.slots.len();
+    let result = context.run_block(&body[..body.len() - 1], Table::default());
+    let backtrace = match result {
+        Ok(signal) => return Ok(signal),
+        Err(backtrace) => backtrace,
+    };
+
+    // Commands may bail out without cleaning up, restore the frame of `try`.
+    context.scopes.truncate(scopes_count);
+    context.slots.truncate(slots_count);
+
+    let mut table = Table::default();
+    table.insert(
+        identifier.clone(),
+        Variant::TABLE(describe(&backtrace)),
+        Some(catch.mark.clone()),
+    )?;
+    context.run_block(&catch_statement[2..], table)
+}
@@ -1,10 +1,14 @@
 pub mod boolean;
 pub mod closure;
 pub mod command;
+pub mod conversion;
 pub mod float;
+pub mod int;
 pub mod list;
 pub mod null;
 pub mod represent;
+#[cfg(feature = "serde")]
+pub mod serialization;
 pub mod strand;
 pub mod table;
 pub mod variant_ops;
@@ -15,6 +19,7 @@ use boolean::Boolean;
 use closure::Closure;
 use command::Command;
 use float::Float;
+use int::Int;
 use list::List;
 use null::Null;
 use represent::Represent;
@@ -43,6 +48,7 @@ macro_rules! mutex_lock_unwrap {
 pub enum Variant {
     NULL(Null),
     BOOL(Boolean),
+    INT(Int),
     FLOAT(Float),
     STRAND(Strand),
     LIST(List),
@@ -52,136 +58,190 @@ pub enum Variant {
 }
 
 impl VariantAdd for Variant {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match self {
-            Variant::NULL(null) => null.add(rhs, mark),
-            Variant::BOOL(boolean) => boolean.add(rhs, mark),
-            Variant::FLOAT(float) => float.add(rhs, mark),
-            Variant::STRAND(strand) => strand.add(rhs, mark),
-            Variant::LIST(list) => list.add(rhs, mark),
-            Variant::TABLE(table) => table.add(rhs, mark),
-            Variant::COMMAND(command) => command.add(rhs, mark),
-            Variant::CLOSURE(closure) => closure.add(rhs, mark),
+            Variant::NULL(null) => null.add(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.add(rhs, mark, context),
+            Variant::INT(int) => int.add(rhs, mark, context),
+            Variant::FLOAT(float) => float.add(rhs, mark, context),
+            Variant::STRAND(strand) => strand.add(rhs, mark, context),
+            Variant::LIST(list) => list.add(rhs, mark, context),
+            Variant::TABLE(table) => table.add(rhs, mark, context),
+            Variant::COMMAND(command) => command.add(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.add(rhs, mark, context),
         }
     }
 }
 
 impl VariantSub for Variant {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match self {
-            Variant::NULL(null) => null.sub(rhs, mark),
-            Variant::BOOL(boolean) => boolean.sub(rhs, mark),
-            Variant::FLOAT(float) => float.sub(rhs, mark),
-            Variant::STRAND(strand) => strand.sub(rhs, mark),
-            Variant::LIST(list) => list.sub(rhs, mark),
-            Variant::TABLE(table) => table.sub(rhs, mark),
-            Variant::COMMAND(command) => command.sub(rhs, mark),
-            Variant::CLOSURE(closure) => closure.sub(rhs, mark),
+            Variant::NULL(null) => null.sub(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.sub(rhs, mark, context),
+            Variant::INT(int) => int.sub(rhs, mark, context),
+            Variant::FLOAT(float) => float.sub(rhs, mark, context),
+            Variant::STRAND(strand) => strand.sub(rhs, mark, context),
+            Variant::LIST(list) => list.sub(rhs, mark, context),
+            Variant::TABLE(table) => table.sub(rhs, mark, context),
+            Variant::COMMAND(command) => command.sub(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.sub(rhs, mark, context),
         }
     }
 }
 
 impl VariantMul for Variant {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match self {
-            Variant::NULL(null) => null.mul(rhs, mark),
-            Variant::BOOL(boolean) => boolean.mul(rhs, mark),
-            Variant::FLOAT(float) => float.mul(rhs, mark),
-            Variant::STRAND(strand) => strand.mul(rhs, mark),
-            Variant::LIST(list) => list.mul(rhs, mark),
-            Variant::TABLE(table) => table.mul(rhs, mark),
-            Variant::COMMAND(command) => command.mul(rhs, mark),
-            Variant::CLOSURE(closure) => closure.mul(rhs, mark),
+            Variant::NULL(null) => null.mul(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.mul(rhs, mark, context),
+            Variant::INT(int) => int.mul(rhs, mark, context),
+            Variant::FLOAT(float) => float.mul(rhs, mark, context),
+            Variant::STRAND(strand) => strand.mul(rhs, mark, context),
+            Variant::LIST(list) => list.mul(rhs, mark, context),
+            Variant::TABLE(table) => table.mul(rhs, mark, context),
+            Variant::COMMAND(command) => command.mul(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.mul(rhs, mark, context),
         }
     }
 }
 
 impl VariantDiv for Variant {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match self {
-            Variant::NULL(null) => null.div(rhs, mark),
-            Variant::BOOL(boolean) => boolean.div(rhs, mark),
-            Variant::FLOAT(float) => float.div(rhs, mark),
-            Variant::STRAND(strand) => strand.div(rhs, mark),
-            Variant::LIST(list) => list.div(rhs, mark),
-            Variant::TABLE(table) => table.div(rhs, mark),
-            Variant::COMMAND(command) => command.div(rhs, mark),
-            Variant::CLOSURE(closure) => closure.div(rhs, mark),
+            Variant::NULL(null) => null.div(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.div(rhs, mark, context),
+            Variant::INT(int) => int.div(rhs, mark, context),
+            Variant::FLOAT(float) => float.div(rhs, mark, context),
+            Variant::STRAND(strand) => strand.div(rhs, mark, context),
+            Variant::LIST(list) => list.div(rhs, mark, context),
+            Variant::TABLE(table) => table.div(rhs, mark, context),
+            Variant::COMMAND(command) => command.div(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.div(rhs, mark, context),
         }
     }
 }
 
 impl VariantEq for Variant {
-    fn eq(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match self {
-            Variant::NULL(null) => null.eq(rhs, mark),
-            Variant::BOOL(boolean) => boolean.eq(rhs, mark),
-            Variant::FLOAT(float) => float.eq(rhs, mark),
-            Variant::STRAND(strand) => strand.eq(rhs, mark),
-            Variant::LIST(list) => list.eq(rhs, mark),
-            Variant::TABLE(table) => table.eq(rhs, mark),
-            Variant::COMMAND(command) => command.eq(rhs, mark),
-            Variant::CLOSURE(closure) => closure.eq(rhs, mark),
+            Variant::NULL(null) => null.eq(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.eq(rhs, mark, context),
+            Variant::INT(int) => int.eq(rhs, mark, context),
+            Variant::FLOAT(float) => float.eq(rhs, mark, context),
+            Variant::STRAND(strand) => strand.eq(rhs, mark, context),
+            Variant::LIST(list) => list.eq(rhs, mark, context),
+            Variant::TABLE(table) => table.eq(rhs, mark, context),
+            Variant::COMMAND(command) => command.eq(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.eq(rhs, mark, context),
         }
     }
 }
 
 impl VariantGe for Variant {
-    fn ge(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match self {
-            Variant::NULL(null) => null.ge(rhs, mark),
-            Variant::BOOL(boolean) => boolean.ge(rhs, mark),
-            Variant::FLOAT(float) => float.ge(rhs, mark),
-            Variant::STRAND(strand) => strand.ge(rhs, mark),
-            Variant::LIST(list) => list.ge(rhs, mark),
-            Variant::TABLE(table) => table.ge(rhs, mark),
-            Variant::COMMAND(command) => command.ge(rhs, mark),
-            Variant::CLOSURE(closure) => closure.ge(rhs, mark),
+            Variant::NULL(null) => null.ge(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.ge(rhs, mark, context),
+            Variant::INT(int) => int.ge(rhs, mark, context),
+            Variant::FLOAT(float) => float.ge(rhs, mark, context),
+            Variant::STRAND(strand) => strand.ge(rhs, mark, context),
+            Variant::LIST(list) => list.ge(rhs, mark, context),
+            Variant::TABLE(table) => table.ge(rhs, mark, context),
+            Variant::COMMAND(command) => command.ge(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.ge(rhs, mark, context),
         }
     }
 }
 
 impl VariantG for Variant {
-    fn g(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match self {
-            Variant::NULL(null) => null.g(rhs, mark),
-            Variant::BOOL(boolean) => boolean.g(rhs, mark),
-            Variant::FLOAT(float) => float.g(rhs, mark),
-            Variant::STRAND(strand) => strand.g(rhs, mark),
-            Variant::LIST(list) => list.g(rhs, mark),
-            Variant::TABLE(table) => table.g(rhs, mark),
-            Variant::COMMAND(command) => command.g(rhs, mark),
-            Variant::CLOSURE(closure) => closure.g(rhs, mark),
+            Variant::NULL(null) => null.g(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.g(rhs, mark, context),
+            Variant::INT(int) => int.g(rhs, mark, context),
+            Variant::FLOAT(float) => float.g(rhs, mark, context),
+            Variant::STRAND(strand) => strand.g(rhs, mark, context),
+            Variant::LIST(list) => list.g(rhs, mark, context),
+            Variant::TABLE(table) => table.g(rhs, mark, context),
+            Variant::COMMAND(command) => command.g(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.g(rhs, mark, context),
         }
     }
 }
 
 impl VariantLe for Variant {
-    fn le(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match self {
-            Variant::NULL(null) => null.le(rhs, mark),
-            Variant::BOOL(boolean) => boolean.le(rhs, mark),
-            Variant::FLOAT(float) => float.le(rhs, mark),
-            Variant::STRAND(strand) => strand.le(rhs, mark),
-            Variant::LIST(list) => list.le(rhs, mark),
-            Variant::TABLE(table) => table.le(rhs, mark),
-            Variant::COMMAND(command) => command.le(rhs, mark),
-            Variant::CLOSURE(closure) => closure.le(rhs, mark),
+            Variant::NULL(null) => null.le(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.le(rhs, mark, context),
+            Variant::INT(int) => int.le(rhs, mark, context),
+            Variant::FLOAT(float) => float.le(rhs, mark, context),
+            Variant::STRAND(strand) => strand.le(rhs, mark, context),
+            Variant::LIST(list) => list.le(rhs, mark, context),
+            Variant::TABLE(table) => table.le(rhs, mark, context),
+            Variant::COMMAND(command) => command.le(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.le(rhs, mark, context),
         }
     }
 }
 
 impl VariantL for Variant {
-    fn l(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match self {
-            Variant::NULL(null) => null.l(rhs, mark),
-            Variant::BOOL(boolean) => boolean.l(rhs, mark),
-            Variant::FLOAT(float) => float.l(rhs, mark),
-            Variant::STRAND(strand) => strand.l(rhs, mark),
-            Variant::LIST(list) => list.l(rhs, mark),
-            Variant::TABLE(table) => table.l(rhs, mark),
-            Variant::COMMAND(command) => command.l(rhs, mark),
-            Variant::CLOSURE(closure) => closure.l(rhs, mark),
+            Variant::NULL(null) => null.l(rhs, mark, context),
+            Variant::BOOL(boolean) => boolean.l(rhs, mark, context),
+            Variant::INT(int) => int.l(rhs, mark, context),
+            Variant::FLOAT(float) => float.l(rhs, mark, context),
+            Variant::STRAND(strand) => strand.l(rhs, mark, context),
+            Variant::LIST(list) => list.l(rhs, mark, context),
+            Variant::TABLE(table) => table.l(rhs, mark, context),
+            Variant::COMMAND(command) => command.l(rhs, mark, context),
+            Variant::CLOSURE(closure) => closure.l(rhs, mark, context),
         }
     }
 }
@@ -191,6 +251,7 @@ impl VariantDuplicate for Variant {
         match self {
             Variant::NULL(null) => null.duplicate(mark, context),
             Variant::BOOL(boolean) => boolean.duplicate(mark, context),
+            Variant::INT(int) => int.duplicate(mark, context),
             Variant::FLOAT(float) => float.duplicate(mark, context),
             Variant::STRAND(strand) => strand.duplicate(mark, context),
             Variant::LIST(list) => list.duplicate(mark, context),
@@ -206,6 +267,7 @@ impl Debug for Variant {
         match self {
             Variant::NULL(null) => f.write_fmt(format_args!("{:?}", null)),
             Variant::BOOL(boolean) => f.write_fmt(format_args!("{:?}", boolean)),
+            Variant::INT(int) => f.write_fmt(format_args!("{:?}", int)),
             Variant::FLOAT(float) => f.write_fmt(format_args!("{:?}", float)),
             Variant::STRAND(strand) => f.write_fmt(format_args!("{:?}", strand)),
             Variant::LIST(list) => f.write_fmt(format_args!("{:?}", list)),
@@ -221,6 +283,7 @@ impl Represent for Variant {
         match self {
             Variant::NULL(null) => null.represent(mark),
             Variant::BOOL(boolean) => boolean.represent(mark),
+            Variant::INT(int) => int.represent(mark),
             Variant::FLOAT(float) => float.represent(mark),
             Variant::STRAND(strand) => strand.represent(mark),
             Variant::LIST(list) => list.represent(mark),
@@ -229,4 +292,12 @@ impl Represent for Variant {
             Variant::CLOSURE(closure) => closure.represent(mark),
         }
     }
+
+    fn represent_in(&self, mark: Option<Mark>, context: &mut Context) -> Result<String, Backtrace> {
+        match self {
+            Variant::LIST(list) => list.represent_in(mark, context),
+            Variant::TABLE(table) => table.represent_in(mark, context),
+            _ => self.represent(mark),
+        }
+    }
 }
@@ -12,7 +12,12 @@ use std::fmt::Debug;
 pub struct Boolean(bool);
 
 impl VariantAdd for Boolean {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -27,7 +32,12 @@ impl VariantAdd for Boolean {
 }
 
 impl VariantSub for Boolean {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -42,7 +52,12 @@ impl VariantSub for Boolean {
 }
 
 impl VariantMul for Boolean {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -57,7 +72,12 @@ impl VariantMul for Boolean {
 }
 
 impl VariantDiv for Boolean {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -72,7 +92,12 @@ impl VariantDiv for Boolean {
 }
 
 impl VariantEq for Boolean {
-    fn eq(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::BOOL(boolean) => Ok(self.0 == boolean.0),
             _ => Ok(false),
@@ -81,7 +106,12 @@ impl VariantEq for Boolean {
 }
 
 impl VariantGe for Boolean {
-    fn ge(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::BOOL(boolean) => Ok(self.0 == boolean.0),
             _ => Ok(false),
@@ -90,7 +120,12 @@ impl VariantGe for Boolean {
 }
 
 impl VariantG for Boolean {
-    fn g(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
@@ -98,7 +133,12 @@ impl VariantG for Boolean {
 }
 
 impl VariantLe for Boolean {
-    fn le(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::BOOL(boolean) => Ok(self.0 == boolean.0),
             _ => Ok(false),
@@ -107,7 +147,12 @@ impl VariantLe for Boolean {
 }
 
 impl VariantL for Boolean {
-    fn l(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
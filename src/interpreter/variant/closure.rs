@@ -8,12 +8,16 @@ use super::Variant;
 use crate::backtrace::Backtrace;
 use crate::interpreter::context::Context;
 use crate::interpreter::signal::Signal;
+use crate::log::Log;
 use crate::mark::Mark;
-use crate::parser::atom::Atom;
+use crate::parser::atom::{Atom, AtomValue};
 use crate::raise_error;
 use std::fmt::Debug;
 use std::mem;
 
+/// Suffix that marks an identifier in the arguments as the keyword of the following argument.
+pub const KEYWORD_SUFFIX: &str = ":";
+
 #[derive(Clone)]
 pub struct Closure {
     pub mark: Mark,
@@ -22,7 +26,12 @@ pub struct Closure {
 }
 
 impl VariantAdd for Closure {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -37,7 +46,12 @@ impl VariantAdd for Closure {
 }
 
 impl VariantSub for Closure {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -52,7 +66,12 @@ impl VariantSub for Closure {
 }
 
 impl VariantMul for Closure {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -67,7 +86,12 @@ impl VariantMul for Closure {
 }
 
 impl VariantDiv for Closure {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -82,25 +106,40 @@ impl VariantDiv for Closure {
 }
 
 impl VariantEq for Closure {
-    fn eq(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
-            Variant::CLOSURE(closure) => self.is_closure_eq(closure, mark),
+            Variant::CLOSURE(closure) => self.is_closure_eq(closure, mark, context),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantGe for Closure {
-    fn ge(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
-            Variant::CLOSURE(closure) => self.is_closure_eq(closure, mark),
+            Variant::CLOSURE(closure) => self.is_closure_eq(closure, mark, context),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantG for Closure {
-    fn g(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
@@ -108,16 +147,26 @@ impl VariantG for Closure {
 }
 
 impl VariantLe for Closure {
-    fn le(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
-            Variant::CLOSURE(closure) => self.is_closure_eq(closure, mark),
+            Variant::CLOSURE(closure) => self.is_closure_eq(closure, mark, context),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantL for Closure {
-    fn l(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
@@ -154,18 +203,125 @@ impl Represent for Closure {
 }
 
 impl Closure {
-    pub fn call_mut(&mut self, context: &mut Context, body: &[Atom]) -> Result<Signal, Backtrace> {
+    /// Call the closure with the arguments in `body`, where `name: value` passes `value` as the
+    /// keyword argument `name`.
+    pub fn call_mut(
+        &mut self,
+        context: &mut Context,
+        head: &Atom,
+        body: &[Atom],
+    ) -> Result<Signal, Backtrace> {
         let mut slots: Vec<Variant> = Vec::new();
-        for atom in body.iter() {
-            let value = context.resolve_variant(atom)?;
-            slots.push(value);
+        let mut keywords: Vec<(String, Variant)> = Vec::new();
+        let mut atoms = body.iter();
+        while let Some(atom) = atoms.next() {
+            let keyword = match atom.value {
+                AtomValue::IDENTIFIER(ref identifier)
+                    if identifier.len() > KEYWORD_SUFFIX.len() =>
+                {
+                    identifier.strip_suffix(KEYWORD_SUFFIX)
+                }
+                _ => None,
+            };
+            let keyword = match keyword {
+                Some(keyword) => keyword,
+                None => {
+                    slots.push(context.resolve_variant(atom)?);
+                    continue;
+                }
+            };
+            if keywords.iter().any(|(name, _)| name == keyword) {
+                raise_error!(
+                    Some(atom.mark.clone()),
+                    "Keyword argument '{}' is given more than once.",
+                    keyword
+                );
+            }
+            let value = match atoms.next() {
+                Some(value) => context.resolve_variant(value)?,
+                None => {
+                    raise_error!(
+                        Some(atom.mark.clone()),
+                        "Keyword argument '{}' is missing its value.",
+                        keyword
+                    );
+                }
+            };
+            keywords.push((String::from(keyword), value));
+        }
+        self.call_frame(context, slots, keywords, Some(head.mark.clone()))
+    }
+
+    /// Call the closure with arguments that are already resolved.
+    pub fn call_with(
+        &mut self,
+        context: &mut Context,
+        slots: Vec<Variant>,
+    ) -> Result<Signal, Backtrace> {
+        self.call_frame(context, slots, Vec::new(), None)
+    }
+
+    fn call_frame(
+        &mut self,
+        context: &mut Context,
+        mut slots: Vec<Variant>,
+        mut keywords: Vec<(String, Variant)>,
+        mut call_mark: Option<Mark>,
+    ) -> Result<Signal, Backtrace> {
+        // Enter the call frame, the caller's arguments and scopes are held by the closure meanwhile.
+        mem::swap(&mut context.slots, &mut slots);
+        mem::swap(&mut context.keywords, &mut keywords);
+        mem::swap(&mut context.call_mark, &mut call_mark);
+        mem::swap(&mut context.scopes, &mut self.parent_scopes); // Install parent scopes into the context.
+        let result = context.run_statements(&self.commands, Table::default());
+        mem::swap(&mut context.scopes, &mut self.parent_scopes); // Retrieve parent scopes back.
+        mem::swap(&mut context.call_mark, &mut call_mark);
+        mem::swap(&mut context.keywords, &mut keywords);
+        mem::swap(&mut context.slots, &mut slots);
+
+        // Arguments are consumed by `parameter`, a closure without it takes none.
+        let signal = result?;
+        if !slots.is_empty() || !keywords.is_empty() {
+            let message = match keywords.first() {
+                Some((keyword, _)) if slots.is_empty() => {
+                    format!("Unknown keyword argument '{}'.", keyword)
+                }
+                _ => format!("Expecting 0 argument(s), but {} are given.", slots.len()),
+            };
+            return match call_mark {
+                Some(call_mark) => Err(Backtrace::new(Log::error(message, Some(call_mark)))
+                    .with_note(Log::note(
+                        String::from("The closure is declared here."),
+                        Some(self.mark.clone()),
+                    ))),
+                None => Err(Backtrace::new(Log::error(message, Some(self.mark.clone())))),
+            };
+        }
+
+        // `return` ends the call frame, it must not leak into the caller's statements.
+        match signal {
+            Signal::RETURN(value, _) => Ok(Signal::COMPLETE(value)),
+            signal => Ok(signal),
+        }
+    }
+
+    /// Call the closure as a callback of a command, tracing errors back to the command at `mark`.
+    pub fn call_back(
+        &mut self,
+        context: &mut Context,
+        slots: Vec<Variant>,
+        mark: Mark,
+    ) -> Result<Variant, Backtrace> {
+        match self.call_frame(context, slots, Vec::new(), Some(mark.clone())) {
+            Ok(Signal::COMPLETE(value)) => Ok(value),
+            Ok(_) => {
+                raise_error!(Some(mark), "Unexpected control command from callback.");
+            }
+            Err(mut backtrace) => {
+                backtrace.push(Log::trace(mark));
+                Err(backtrace)
+            }
         }
-        let mut closure_context = Context::new()?;
-        closure_context.slots = slots;
-        mem::swap(&mut closure_context.scopes, &mut self.parent_scopes); // Install parent scopes into the context.
-        let result = closure_context.run_statements(&self.commands, Table::default());
-        mem::swap(&mut closure_context.scopes, &mut self.parent_scopes); // Retrieve parent scopes back.
-        result
     }
 
     pub fn new(mark: Mark, commands: Vec<Atom>, parent_scopes: Vec<Table>) -> Self {
@@ -176,7 +332,12 @@ impl Closure {
         }
     }
 
-    pub fn is_closure_eq(&self, other: &Self, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    pub fn is_closure_eq(
+        &self,
+        other: &Self,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         // Comparing parent scope.
         if self.parent_scopes.len() != other.parent_scopes.len() {
             return Ok(false);
@@ -185,7 +346,7 @@ impl Closure {
         for i in 0..self.parent_scopes.len() {
             let self_element = self.parent_scopes.get(i).unwrap();
             let other_element = self.parent_scopes.get(i).unwrap();
-            if !self_element.is_table_eq(other_element, mark.clone())? {
+            if !self_element.is_table_eq(other_element, mark.clone(), context)? {
                 return Ok(false);
             }
         }
@@ -6,6 +6,7 @@ use super::variant_ops::{
 use super::Variant;
 use crate::backtrace::Backtrace;
 use crate::interpreter::context::Context;
+use crate::interpreter::native::NativeFn;
 use crate::interpreter::signal::Signal;
 use crate::mark::Mark;
 use crate::parser::atom::Atom;
@@ -22,7 +23,12 @@ unsafe impl Sync for Command {}
 unsafe impl Send for Command {}
 
 impl VariantAdd for Command {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -37,7 +43,12 @@ impl VariantAdd for Command {
 }
 
 impl VariantSub for Command {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -52,7 +63,12 @@ impl VariantSub for Command {
 }
 
 impl VariantMul for Command {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -67,7 +83,12 @@ impl VariantMul for Command {
 }
 
 impl VariantDiv for Command {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -82,7 +103,12 @@ impl VariantDiv for Command {
 }
 
 impl VariantEq for Command {
-    fn eq(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::COMMAND(command) => Ok(Arc::ptr_eq(&self.callable, &command.callable)),
             _ => Ok(false),
@@ -91,7 +117,12 @@ impl VariantEq for Command {
 }
 
 impl VariantGe for Command {
-    fn ge(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::COMMAND(command) => Ok(Arc::ptr_eq(&self.callable, &command.callable)),
             _ => Ok(false),
@@ -100,7 +131,12 @@ impl VariantGe for Command {
 }
 
 impl VariantG for Command {
-    fn g(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
@@ -108,7 +144,12 @@ impl VariantG for Command {
 }
 
 impl VariantLe for Command {
-    fn le(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::COMMAND(command) => Ok(Arc::ptr_eq(&self.callable, &command.callable)),
             _ => Ok(false),
@@ -117,7 +158,12 @@ impl VariantLe for Command {
 }
 
 impl VariantL for Command {
-    fn l(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
@@ -152,6 +198,16 @@ impl Command {
         }
     }
 
+    /// Wrap a typed Rust function, see [`NativeFn`].
+    pub fn native<A, T>(function: T) -> Self
+    where
+        T: NativeFn<A>,
+    {
+        Command::new(move |context: &mut Context, head: &Atom, body: &[Atom]| {
+            function.call_native(context, head, body)
+        })
+    }
+
     pub fn call(
         &self,
         context: &mut Context,
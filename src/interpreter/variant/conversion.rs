@@ -0,0 +1,290 @@
+use super::boolean::Boolean;
+use super::closure::Closure;
+use super::command::Command;
+use super::float::Float;
+use super::int::Int;
+use super::list::List;
+use super::null::Null;
+use super::represent::Represent;
+use super::strand::Strand;
+use super::table::Table;
+use super::Variant;
+use crate::backtrace::Backtrace;
+use crate::raise_error;
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+macro_rules! impl_variant_conversion {
+    ($kind:ident, $type:ty, $name:expr) => {
+        impl From<$type> for Variant {
+            fn from(value: $type) -> Self {
+                Variant::$kind(value)
+            }
+        }
+
+        impl TryFrom<Variant> for $type {
+            type Error = Backtrace;
+
+            fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                match value {
+                    Variant::$kind(inner) => Ok(inner),
+                    _ => {
+                        raise_error!(
+                            None,
+                            "Expecting {}, but `{}` is given.",
+                            $name,
+                            value.represent(None)?
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_variant_conversion!(NULL, Null, "a null");
+impl_variant_conversion!(BOOL, Boolean, "a boolean");
+impl_variant_conversion!(INT, Int, "an integer");
+impl_variant_conversion!(FLOAT, Float, "a float");
+impl_variant_conversion!(STRAND, Strand, "a string");
+impl_variant_conversion!(LIST, List, "a list");
+impl_variant_conversion!(TABLE, Table, "a table");
+impl_variant_conversion!(COMMAND, Command, "a command");
+impl_variant_conversion!(CLOSURE, Closure, "a closure");
+
+impl From<Infallible> for Backtrace {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+impl From<bool> for Variant {
+    fn from(value: bool) -> Self {
+        Variant::BOOL(Boolean::from(value))
+    }
+}
+
+impl TryFrom<Variant> for bool {
+    type Error = Backtrace;
+
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        Ok(Boolean::try_from(value)?.is_true())
+    }
+}
+
+impl From<f64> for Variant {
+    fn from(value: f64) -> Self {
+        Variant::FLOAT(Float::from(value))
+    }
+}
+
+impl TryFrom<Variant> for f64 {
+    type Error = Backtrace;
+
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        match value {
+            Variant::INT(int) => Ok(Into::<i64>::into(int) as f64),
+            _ => Ok(Float::try_from(value)?.into()),
+        }
+    }
+}
+
+impl From<String> for Variant {
+    fn from(value: String) -> Self {
+        Variant::STRAND(Strand::from(value))
+    }
+}
+
+impl<'a> From<&'a str> for Variant {
+    fn from(value: &'a str) -> Self {
+        Variant::STRAND(Strand::from(value))
+    }
+}
+
+impl TryFrom<Variant> for String {
+    type Error = Backtrace;
+
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        Ok(Strand::try_from(value)?.into())
+    }
+}
+
+impl From<f32> for Variant {
+    fn from(value: f32) -> Self {
+        Variant::FLOAT(Float::from(value as f64))
+    }
+}
+
+impl TryFrom<Variant> for f32 {
+    type Error = Backtrace;
+
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        Ok(f64::try_from(value)? as f32)
+    }
+}
+
+macro_rules! impl_integer_conversion {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Variant {
+                fn from(value: $type) -> Self {
+                    // Only `u64` and `usize` may not fit, they fall back to float.
+                    match i64::try_from(value) {
+                        Ok(int) => Variant::INT(Int::from(int)),
+                        Err(_) => Variant::FLOAT(Float::from(value as f64)),
+                    }
+                }
+            }
+
+            impl TryFrom<Variant> for $type {
+                type Error = Backtrace;
+
+                fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                    let int: i64 = Int::try_from(value)?.into();
+                    match <$type>::try_from(int) {
+                        Ok(value) => Ok(value),
+                        Err(_) => {
+                            raise_error!(
+                                None,
+                                "Expecting {}, but `{}` is given.",
+                                stringify!($type),
+                                int
+                            );
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_conversion!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<()> for Variant {
+    fn from(_value: ()) -> Self {
+        Variant::NULL(Null())
+    }
+}
+
+impl TryFrom<Variant> for () {
+    type Error = Backtrace;
+
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        Null::try_from(value)?;
+        Ok(())
+    }
+}
+
+impl<T> From<Option<T>> for Variant
+where
+    T: Into<Variant>,
+{
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => Variant::NULL(Null()),
+        }
+    }
+}
+
+// A generic implementation would conflict with `TryFrom<Variant> for Option<Variant>` from core.
+macro_rules! impl_option_conversion {
+    ($type:ty $(, $generic:ident)*) => {
+        impl<$($generic),*> TryFrom<Variant> for Option<$type>
+        where
+            $type: TryFrom<Variant>,
+            <$type as TryFrom<Variant>>::Error: Into<Backtrace>,
+        {
+            type Error = Backtrace;
+
+            fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                match value {
+                    Variant::NULL(_) => Ok(None),
+                    _ => <$type>::try_from(value).map(Some).map_err(Into::into),
+                }
+            }
+        }
+    };
+}
+
+impl_option_conversion!(Boolean);
+impl_option_conversion!(Int);
+impl_option_conversion!(Float);
+impl_option_conversion!(Strand);
+impl_option_conversion!(List);
+impl_option_conversion!(Table);
+impl_option_conversion!(Command);
+impl_option_conversion!(Closure);
+impl_option_conversion!(bool);
+impl_option_conversion!(f32);
+impl_option_conversion!(f64);
+impl_option_conversion!(String);
+impl_option_conversion!(i8);
+impl_option_conversion!(i16);
+impl_option_conversion!(i32);
+impl_option_conversion!(i64);
+impl_option_conversion!(isize);
+impl_option_conversion!(u8);
+impl_option_conversion!(u16);
+impl_option_conversion!(u32);
+impl_option_conversion!(u64);
+impl_option_conversion!(usize);
+impl_option_conversion!(Vec<T>, T);
+impl_option_conversion!(HashMap<String, T>, T);
+
+impl<T> From<Vec<T>> for Variant
+where
+    T: Into<Variant>,
+{
+    fn from(value: Vec<T>) -> Self {
+        Variant::LIST(List::from(
+            value.into_iter().map(Into::into).collect::<Vec<Variant>>(),
+        ))
+    }
+}
+
+impl<T> TryFrom<Variant> for Vec<T>
+where
+    T: TryFrom<Variant>,
+    T::Error: Into<Backtrace>,
+{
+    type Error = Backtrace;
+
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        List::try_from(value)?
+            .to_vec(None)?
+            .into_iter()
+            .map(|element| T::try_from(element).map_err(Into::into))
+            .collect()
+    }
+}
+
+impl<T> From<HashMap<String, T>> for Variant
+where
+    T: Into<Variant>,
+{
+    fn from(value: HashMap<String, T>) -> Self {
+        Variant::TABLE(Table::from(
+            value
+                .into_iter()
+                .map(|(key, value)| (key, value.into()))
+                .collect::<HashMap<String, Variant>>(),
+        ))
+    }
+}
+
+impl<T> TryFrom<Variant> for HashMap<String, T>
+where
+    T: TryFrom<Variant>,
+    T::Error: Into<Backtrace>,
+{
+    type Error = Backtrace;
+
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        Table::try_from(value)?
+            .entries(None)?
+            .into_iter()
+            .map(|(key, value)| Ok((key, T::try_from(value).map_err(Into::into)?)))
+            .collect()
+    }
+}
@@ -13,16 +13,25 @@ use std::fmt::Debug;
 pub struct Float(f64);
 
 impl VariantAdd for Float {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => {
-                let rhs_float: f64 = float.clone().into();
+                let rhs_float: f64 = (*float).into();
                 Ok(Variant::FLOAT(Float::from(self.0 + rhs_float)))
             }
+            Variant::INT(int) => {
+                let rhs_int: i64 = (*int).into();
+                Ok(Variant::FLOAT(Float::from(self.0 + rhs_int as f64)))
+            }
             Variant::STRAND(strand) => {
                 let mut self_string = self.represent(mark)?;
-                let mut rhs_string: String = strand.clone().into();
-                self_string.push_str(&mut rhs_string);
+                let rhs_string: String = strand.clone().into();
+                self_string.push_str(&rhs_string);
                 Ok(Variant::STRAND(Strand::from(self_string)))
             }
             _ => {
@@ -38,12 +47,21 @@ impl VariantAdd for Float {
 }
 
 impl VariantSub for Float {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => {
-                let rhs_float: f64 = float.clone().into();
+                let rhs_float: f64 = (*float).into();
                 Ok(Variant::FLOAT(Float::from(self.0 - rhs_float)))
             }
+            Variant::INT(int) => {
+                let rhs_int: i64 = (*int).into();
+                Ok(Variant::FLOAT(Float::from(self.0 - rhs_int as f64)))
+            }
             _ => {
                 raise_error!(
                     mark.clone(),
@@ -57,12 +75,21 @@ impl VariantSub for Float {
 }
 
 impl VariantMul for Float {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => {
-                let rhs_float: f64 = float.clone().into();
+                let rhs_float: f64 = (*float).into();
                 Ok(Variant::FLOAT(Float::from(self.0 * rhs_float)))
             }
+            Variant::INT(int) => {
+                let rhs_int: i64 = (*int).into();
+                Ok(Variant::FLOAT(Float::from(self.0 * rhs_int as f64)))
+            }
             _ => {
                 raise_error!(
                     mark.clone(),
@@ -76,12 +103,27 @@ impl VariantMul for Float {
 }
 
 impl VariantDiv for Float {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => {
-                let rhs_float: f64 = float.clone().into();
+                let rhs_float: f64 = (*float).into();
+                if rhs_float == 0.0 {
+                    raise_error!(mark, "Dividing `{}` by zero.", self.0);
+                }
                 Ok(Variant::FLOAT(Float::from(self.0 / rhs_float)))
             }
+            Variant::INT(int) => {
+                let rhs_int: i64 = (*int).into();
+                if rhs_int == 0 {
+                    raise_error!(mark, "Dividing `{}` by zero.", self.0);
+                }
+                Ok(Variant::FLOAT(Float::from(self.0 / rhs_int as f64)))
+            }
             _ => {
                 raise_error!(
                     mark.clone(),
@@ -95,45 +137,75 @@ impl VariantDiv for Float {
 }
 
 impl VariantEq for Float {
-    fn eq(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => Ok(self.0 == float.0),
+            Variant::INT(int) => Ok(self.0 == Into::<i64>::into(*int) as f64),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantGe for Float {
-    fn ge(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => Ok(self.0 >= float.0),
+            Variant::INT(int) => Ok(self.0 >= Into::<i64>::into(*int) as f64),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantG for Float {
-    fn g(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => Ok(self.0 > float.0),
+            Variant::INT(int) => Ok(self.0 > Into::<i64>::into(*int) as f64),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantLe for Float {
-    fn le(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => Ok(self.0 <= float.0),
+            Variant::INT(int) => Ok(self.0 <= Into::<i64>::into(*int) as f64),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantL for Float {
-    fn l(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::FLOAT(float) => Ok(self.0 < float.0),
+            Variant::INT(int) => Ok(self.0 < Into::<i64>::into(*int) as f64),
             _ => Ok(false),
         }
     }
@@ -141,7 +213,7 @@ impl VariantL for Float {
 
 impl VariantDuplicate for Float {
     fn duplicate(&self, _mark: Option<Mark>, _context: &mut Context) -> Result<Variant, Backtrace> {
-        Ok(Variant::FLOAT(self.clone()))
+        Ok(Variant::FLOAT(*self))
     }
 }
 
@@ -153,7 +225,8 @@ impl Debug for Float {
 
 impl Represent for Float {
     fn represent(&self, _mark: Option<Mark>) -> Result<String, Backtrace> {
-        Ok(format!("{}", self.0))
+        // Debug formatting keeps the fraction, so `2.0` is not mistaken for an integer.
+        Ok(format!("{:?}", self.0))
     }
 }
 
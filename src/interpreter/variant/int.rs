@@ -0,0 +1,302 @@
+use super::float::Float;
+use super::strand::Strand;
+use super::variant_ops::{
+    VariantAdd, VariantDiv, VariantDuplicate, VariantEq, VariantG, VariantGe, VariantL, VariantLe,
+    VariantMul, VariantSub,
+};
+use super::{represent::Represent, Variant};
+use crate::interpreter::context::Context;
+use crate::mark::Mark;
+use crate::{backtrace::Backtrace, raise_error};
+use std::fmt::Debug;
+
+#[derive(Clone, Copy)]
+pub struct Int(i64);
+
+impl VariantAdd for Int {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        match rhs {
+            Variant::INT(int) => match self.0.checked_add(int.0) {
+                Some(result) => Ok(Variant::INT(Int::from(result))),
+                None => {
+                    raise_error!(mark, "Adding `{}` with `{}` overflows.", self.0, int.0);
+                }
+            },
+            Variant::FLOAT(float) => {
+                let rhs_float: f64 = (*float).into();
+                Ok(Variant::FLOAT(Float::from(self.0 as f64 + rhs_float)))
+            }
+            Variant::STRAND(strand) => {
+                let mut self_string = self.represent(mark)?;
+                let rhs_string: String = strand.clone().into();
+                self_string.push_str(&rhs_string);
+                Ok(Variant::STRAND(Strand::from(self_string)))
+            }
+            _ => {
+                raise_error!(
+                    mark.clone(),
+                    "`{}` cannot be added with `{}`.",
+                    self.represent(mark.clone())?,
+                    rhs.represent(mark.clone())?
+                );
+            }
+        }
+    }
+}
+
+impl VariantSub for Int {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        match rhs {
+            Variant::INT(int) => match self.0.checked_sub(int.0) {
+                Some(result) => Ok(Variant::INT(Int::from(result))),
+                None => {
+                    raise_error!(mark, "Subtracting `{}` with `{}` overflows.", self.0, int.0);
+                }
+            },
+            Variant::FLOAT(float) => {
+                let rhs_float: f64 = (*float).into();
+                Ok(Variant::FLOAT(Float::from(self.0 as f64 - rhs_float)))
+            }
+            _ => {
+                raise_error!(
+                    mark.clone(),
+                    "`{}` cannot be subtracted with `{}`.",
+                    self.represent(mark.clone())?,
+                    rhs.represent(mark.clone())?
+                );
+            }
+        }
+    }
+}
+
+impl VariantMul for Int {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        match rhs {
+            Variant::INT(int) => match self.0.checked_mul(int.0) {
+                Some(result) => Ok(Variant::INT(Int::from(result))),
+                None => {
+                    raise_error!(mark, "Multiplying `{}` with `{}` overflows.", self.0, int.0);
+                }
+            },
+            Variant::FLOAT(float) => {
+                let rhs_float: f64 = (*float).into();
+                Ok(Variant::FLOAT(Float::from(self.0 as f64 * rhs_float)))
+            }
+            _ => {
+                raise_error!(
+                    mark.clone(),
+                    "`{}` cannot be multiplied with `{}`.",
+                    self.represent(mark.clone())?,
+                    rhs.represent(mark.clone())?
+                );
+            }
+        }
+    }
+}
+
+impl VariantDiv for Int {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        match rhs {
+            Variant::INT(int) => {
+                if int.0 == 0 {
+                    raise_error!(mark, "Dividing `{}` by zero.", self.0);
+                }
+                // Exact quotients stay integers, the rest are promoted to float.
+                match self.0.checked_rem(int.0) {
+                    Some(0) => match self.0.checked_div(int.0) {
+                        Some(result) => Ok(Variant::INT(Int::from(result))),
+                        None => {
+                            raise_error!(mark, "Dividing `{}` with `{}` overflows.", self.0, int.0);
+                        }
+                    },
+                    Some(_) => Ok(Variant::FLOAT(Float::from(self.0 as f64 / int.0 as f64))),
+                    None => {
+                        raise_error!(mark, "Dividing `{}` with `{}` overflows.", self.0, int.0);
+                    }
+                }
+            }
+            Variant::FLOAT(float) => {
+                let rhs_float: f64 = (*float).into();
+                if rhs_float == 0.0 {
+                    raise_error!(mark, "Dividing `{}` by zero.", self.0);
+                }
+                Ok(Variant::FLOAT(Float::from(self.0 as f64 / rhs_float)))
+            }
+            _ => {
+                raise_error!(
+                    mark.clone(),
+                    "`{}` cannot be divided with `{}`.",
+                    self.represent(mark.clone())?,
+                    rhs.represent(mark.clone())?
+                );
+            }
+        }
+    }
+}
+
+impl VariantEq for Int {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        match rhs {
+            Variant::INT(int) => Ok(self.0 == int.0),
+            Variant::FLOAT(float) => Ok(self.0 as f64 == Into::<f64>::into(*float)),
+            _ => Ok(false),
+        }
+    }
+}
+
+impl VariantGe for Int {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        match rhs {
+            Variant::INT(int) => Ok(self.0 >= int.0),
+            Variant::FLOAT(float) => Ok(self.0 as f64 >= Into::<f64>::into(*float)),
+            _ => Ok(false),
+        }
+    }
+}
+
+impl VariantG for Int {
+    fn g(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        match rhs {
+            Variant::INT(int) => Ok(self.0 > int.0),
+            Variant::FLOAT(float) => Ok(self.0 as f64 > Into::<f64>::into(*float)),
+            _ => Ok(false),
+        }
+    }
+}
+
+impl VariantLe for Int {
+    fn le(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        match rhs {
+            Variant::INT(int) => Ok(self.0 <= int.0),
+            Variant::FLOAT(float) => Ok(self.0 as f64 <= Into::<f64>::into(*float)),
+            _ => Ok(false),
+        }
+    }
+}
+
+impl VariantL for Int {
+    fn l(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        match rhs {
+            Variant::INT(int) => Ok(self.0 < int.0),
+            Variant::FLOAT(float) => Ok((self.0 as f64) < Into::<f64>::into(*float)),
+            _ => Ok(false),
+        }
+    }
+}
+
+impl VariantDuplicate for Int {
+    fn duplicate(&self, _mark: Option<Mark>, _context: &mut Context) -> Result<Variant, Backtrace> {
+        Ok(Variant::INT(*self))
+    }
+}
+
+impl Debug for Int {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self.0))
+    }
+}
+
+impl Represent for Int {
+    fn represent(&self, _mark: Option<Mark>) -> Result<String, Backtrace> {
+        Ok(format!("{}", self.0))
+    }
+}
+
+impl Int {
+    /// Position of an element in a sequence of `length`, negative index counts from the end.
+    pub fn as_index(self, length: usize, mark: Option<Mark>) -> Result<usize, Backtrace> {
+        let position = self.as_position(length);
+        match position {
+            Some(position) if position < length => Ok(position),
+            _ => {
+                raise_error!(
+                    mark,
+                    "Index `{}` is out of bound for length {}.",
+                    self.0,
+                    length
+                );
+            }
+        }
+    }
+
+    /// Position between elements of a sequence of `length`, so `length` itself is allowed.
+    pub fn as_bound(self, length: usize, mark: Option<Mark>) -> Result<usize, Backtrace> {
+        let position = self.as_position(length);
+        match position {
+            Some(position) if position <= length => Ok(position),
+            _ => {
+                raise_error!(
+                    mark,
+                    "Index `{}` is out of bound for length {}.",
+                    self.0,
+                    length
+                );
+            }
+        }
+    }
+
+    fn as_position(self, length: usize) -> Option<usize> {
+        if self.0 < 0 {
+            length.checked_sub(usize::try_from(self.0.unsigned_abs()).ok()?)
+        } else {
+            usize::try_from(self.0).ok()
+        }
+    }
+}
+
+impl From<Int> for i64 {
+    fn from(value: Int) -> Self {
+        value.0
+    }
+}
+
+impl From<i64> for Int {
+    fn from(value: i64) -> Self {
+        Int(value)
+    }
+}
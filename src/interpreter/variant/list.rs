@@ -1,4 +1,6 @@
-use super::float::Float;
+use super::closure::Closure;
+use super::int::Int;
+use super::null::Null;
 use super::variant_ops::{
     VariantAdd, VariantDiv, VariantDuplicate, VariantEq, VariantG, VariantGe, VariantL, VariantLe,
     VariantMul, VariantSub,
@@ -15,8 +17,19 @@ use std::sync::{Arc, Mutex};
 pub struct List(Arc<Mutex<Vec<Variant>>>);
 
 impl VariantAdd for List {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
+            Variant::LIST(list) => {
+                // Copy each side separately, adding a list with itself must not lock it twice.
+                let mut elements = self.to_vec(mark.clone())?;
+                elements.append(&mut list.to_vec(mark)?);
+                Ok(Variant::LIST(List::from(elements)))
+            }
             _ => {
                 raise_error!(
                     mark.clone(),
@@ -30,7 +43,12 @@ impl VariantAdd for List {
 }
 
 impl VariantSub for List {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -45,7 +63,12 @@ impl VariantSub for List {
 }
 
 impl VariantMul for List {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -60,7 +83,12 @@ impl VariantMul for List {
 }
 
 impl VariantDiv for List {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -75,25 +103,17 @@ impl VariantDiv for List {
 }
 
 impl VariantEq for List {
-    fn eq(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::LIST(list) => {
-                let self_guard = mutex_lock_unwrap!(self.0, mark.clone());
-                let other_guard = mutex_lock_unwrap!(list.0, mark.clone());
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for i in 0..self_guard.len() {
-                    let self_element = self_guard.get(i).unwrap();
-                    let other_element = other_guard.get(i).unwrap();
-                    if !self_element.eq(other_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-
-                Ok(true)
+                self.compare_elements(list, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.eq(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -101,25 +121,17 @@ impl VariantEq for List {
 }
 
 impl VariantGe for List {
-    fn ge(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::LIST(list) => {
-                let self_guard = mutex_lock_unwrap!(self.0, mark.clone());
-                let other_guard = mutex_lock_unwrap!(list.0, mark.clone());
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for i in 0..self_guard.len() {
-                    let self_element = self_guard.get(i).unwrap();
-                    let other_element = other_guard.get(i).unwrap();
-                    if !self_element.ge(other_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-
-                Ok(true)
+                self.compare_elements(list, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.ge(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -127,25 +139,17 @@ impl VariantGe for List {
 }
 
 impl VariantG for List {
-    fn g(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::LIST(list) => {
-                let self_guard = mutex_lock_unwrap!(self.0, mark.clone());
-                let other_guard = mutex_lock_unwrap!(list.0, mark.clone());
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for i in 0..self_guard.len() {
-                    let self_element = self_guard.get(i).unwrap();
-                    let other_element = other_guard.get(i).unwrap();
-                    if !self_element.g(other_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-
-                Ok(true)
+                self.compare_elements(list, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.g(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -153,25 +157,17 @@ impl VariantG for List {
 }
 
 impl VariantLe for List {
-    fn le(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::LIST(list) => {
-                let self_guard = mutex_lock_unwrap!(self.0, mark.clone());
-                let other_guard = mutex_lock_unwrap!(list.0, mark.clone());
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for i in 0..self_guard.len() {
-                    let self_element = self_guard.get(i).unwrap();
-                    let other_element = other_guard.get(i).unwrap();
-                    if !self_element.le(other_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-
-                Ok(true)
+                self.compare_elements(list, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.le(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -179,25 +175,17 @@ impl VariantLe for List {
 }
 
 impl VariantL for List {
-    fn l(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::LIST(list) => {
-                let self_guard = mutex_lock_unwrap!(self.0, mark.clone());
-                let other_guard = mutex_lock_unwrap!(list.0, mark.clone());
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for i in 0..self_guard.len() {
-                    let self_element = self_guard.get(i).unwrap();
-                    let other_element = other_guard.get(i).unwrap();
-                    if !self_element.l(other_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-
-                Ok(true)
+                self.compare_elements(list, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.l(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -223,15 +211,14 @@ impl Debug for List {
 
 impl Represent for List {
     fn represent(&self, mark: Option<Mark>) -> Result<String, Backtrace> {
-        let guard = mutex_lock_unwrap!(self.0, mark.clone());
-        let representations = guard
-            .iter()
-            .map(|x| match x {
-                Variant::STRAND(strand) => Ok(format!("\"{}\"", strand.as_str())),
-                _ => x.represent(mark.clone()),
-            })
-            .collect::<Result<Vec<String>, Backtrace>>()?;
-        Ok(format!("[{}]", representations.join(", ")))
+        List::format_elements(self.to_vec(mark.clone())?, |x| x.represent(mark.clone()))
+    }
+
+    fn represent_in(&self, mark: Option<Mark>, context: &mut Context) -> Result<String, Backtrace> {
+        // Elements are represented on a copy, as representing may run closures that touch the list.
+        List::format_elements(self.to_vec(mark.clone())?, |x| {
+            x.represent_in(mark.clone(), context)
+        })
     }
 }
 
@@ -260,16 +247,208 @@ impl List {
 
     pub fn length(&self, mark: Option<Mark>) -> Result<Variant, Backtrace> {
         let guard = mutex_lock_unwrap!(self.0, mark);
-        Ok(Variant::FLOAT(Float::from(guard.len() as f64)))
+        Ok(Variant::INT(Int::from(guard.len() as i64)))
+    }
+
+    pub fn to_vec(&self, mark: Option<Mark>) -> Result<Vec<Variant>, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.0, mark);
+        Ok(guard.clone())
     }
 
-    pub fn get(&self, index: Float, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    pub fn get(&self, index: Int, mark: Option<Mark>) -> Result<Variant, Backtrace> {
         let guard = mutex_lock_unwrap!(self.0, mark);
-        let element = guard.get(Into::<f64>::into(index) as usize);
-        if element.is_none() {
-            raise_error!(mark, "Accessing index out of bound.");
+        let index = index.as_index(guard.len(), mark)?;
+        Ok(guard[index].clone())
+    }
+
+    pub fn set(
+        &mut self,
+        index: Int,
+        variant: Variant,
+        mark: Option<Mark>,
+    ) -> Result<(), Backtrace> {
+        let mut guard = mutex_lock_unwrap!(self.0, mark);
+        let index = index.as_index(guard.len(), mark)?;
+        guard[index] = variant;
+        Ok(())
+    }
+
+    pub fn insert(
+        &mut self,
+        index: Int,
+        variant: Variant,
+        mark: Option<Mark>,
+    ) -> Result<(), Backtrace> {
+        let mut guard = mutex_lock_unwrap!(self.0, mark);
+        let index = index.as_bound(guard.len(), mark)?;
+        guard.insert(index, variant);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: Int, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+        let mut guard = mutex_lock_unwrap!(self.0, mark);
+        let index = index.as_index(guard.len(), mark)?;
+        Ok(guard.remove(index))
+    }
+
+    /// New list of the elements from `start` up to, but excluding, `end`.
+    pub fn slice(
+        &self,
+        start: Int,
+        end: Option<Int>,
+        mark: Option<Mark>,
+    ) -> Result<Variant, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.0, mark);
+        let start = start.as_bound(guard.len(), mark.clone())?;
+        let end = match end {
+            Some(end) => end.as_bound(guard.len(), mark.clone())?,
+            None => guard.len(),
+        };
+        if start > end {
+            raise_error!(mark, "Slice starts after it ends.");
+        }
+        Ok(Variant::LIST(List::from(guard[start..end].to_vec())))
+    }
+
+    pub fn reverse(&mut self, mark: Option<Mark>) -> Result<(), Backtrace> {
+        let mut guard = mutex_lock_unwrap!(self.0, mark);
+        guard.reverse();
+        Ok(())
+    }
+
+    /// Index of the first element equal to `variant`, or null if there is none.
+    pub fn index_of(
+        &self,
+        variant: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        // Compare on a copy, the elements may hold the list itself.
+        for (i, element) in self.to_vec(mark.clone())?.iter().enumerate() {
+            if element.eq(variant, mark.clone(), context)? {
+                return Ok(Variant::INT(Int::from(i as i64)));
+            }
+        }
+        Ok(Variant::NULL(Null()))
+    }
+
+    /// Sort the list stably, with `comparator` telling whether its first argument goes before
+    /// the second, or `l` if it is not given.
+    pub fn sort(
+        &mut self,
+        context: &mut Context,
+        comparator: Option<Closure>,
+        mark: Mark,
+    ) -> Result<(), Backtrace> {
+        // The comparator may touch the list, so the list is not locked while sorting.
+        let elements = self.to_vec(Some(mark.clone()))?;
+        let sorted = match comparator {
+            Some(mut comparator) => merge_sort(elements, &mut |lhs, rhs| {
+                let arguments = vec![lhs.clone(), rhs.clone()];
+                match comparator.call_back(context, arguments, mark.clone())? {
+                    Variant::BOOL(boolean) => Ok(boolean.is_true()),
+                    _ => {
+                        raise_error!(Some(mark.clone()), "Comparator must return a boolean.");
+                    }
+                }
+            })?,
+            None => merge_sort(elements, &mut |lhs, rhs| {
+                // `l` is false for operands it cannot order, which would leave them unsorted.
+                let is_ordered = match (lhs, rhs) {
+                    (Variant::INT(_) | Variant::FLOAT(_), Variant::INT(_) | Variant::FLOAT(_)) => {
+                        true
+                    }
+                    (Variant::STRAND(_), Variant::STRAND(_)) => true,
+                    (Variant::TABLE(table), _) => table
+                        .lookup(&String::from("__lt"), Some(mark.clone()))?
+                        .is_some(),
+                    _ => false,
+                };
+                if !is_ordered {
+                    raise_error!(
+                        Some(mark.clone()),
+                        "`{}` and `{}` cannot be ordered without a comparator.",
+                        lhs.represent(Some(mark.clone()))?,
+                        rhs.represent(Some(mark.clone()))?
+                    );
+                }
+                lhs.l(rhs, Some(mark.clone()), context)
+            })?,
+        };
+        let mut guard = mutex_lock_unwrap!(self.0, Some(mark));
+        *guard = sorted;
+        Ok(())
+    }
+}
+
+impl List {
+    fn format_elements<F>(elements: Vec<Variant>, mut represent: F) -> Result<String, Backtrace>
+    where
+        F: FnMut(&Variant) -> Result<String, Backtrace>,
+    {
+        let representations = elements
+            .iter()
+            .map(|x| match x {
+                Variant::STRAND(strand) => Ok(format!("\"{}\"", strand.as_str())),
+                _ => represent(x),
+            })
+            .collect::<Result<Vec<String>, Backtrace>>()?;
+        Ok(format!("[{}]", representations.join(", ")))
+    }
+
+    /// Whether both lists have the same length and `compare` holds for each pair of elements.
+    ///
+    /// Elements are compared on copies, as comparing may run closures that touch the lists.
+    fn compare_elements<F>(
+        &self,
+        other: &Self,
+        mark: Option<Mark>,
+        context: &mut Context,
+        mut compare: F,
+    ) -> Result<bool, Backtrace>
+    where
+        F: FnMut(&Variant, &Variant, &mut Context) -> Result<bool, Backtrace>,
+    {
+        let self_elements = self.to_vec(mark.clone())?;
+        let other_elements = other.to_vec(mark)?;
+
+        if self_elements.len() != other_elements.len() {
+            return Ok(false);
+        }
+
+        for (self_element, other_element) in self_elements.iter().zip(other_elements.iter()) {
+            if !compare(self_element, other_element, context)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Stable merge sort that stops on the first failed comparison.
+fn merge_sort(
+    mut elements: Vec<Variant>,
+    is_less: &mut dyn FnMut(&Variant, &Variant) -> Result<bool, Backtrace>,
+) -> Result<Vec<Variant>, Backtrace> {
+    if elements.len() <= 1 {
+        return Ok(elements);
+    }
+    let right = elements.split_off(elements.len() / 2);
+    let left = merge_sort(elements, is_less)?;
+    let right = merge_sort(right, is_less)?;
+
+    let mut merged: Vec<Variant> = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(lhs), Some(rhs)) = (left.peek(), right.peek()) {
+        // Taking from the left on ties keeps the sort stable.
+        if is_less(rhs, lhs)? {
+            merged.push(right.next().unwrap());
+        } else {
+            merged.push(left.next().unwrap());
         }
-        let element = element.unwrap();
-        Ok(element.clone())
     }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
 }
@@ -12,7 +12,12 @@ use std::fmt::Debug;
 pub struct Null();
 
 impl VariantAdd for Null {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -27,7 +32,12 @@ impl VariantAdd for Null {
 }
 
 impl VariantSub for Null {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -42,7 +52,12 @@ impl VariantSub for Null {
 }
 
 impl VariantMul for Null {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -57,7 +72,12 @@ impl VariantMul for Null {
 }
 
 impl VariantDiv for Null {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -72,7 +92,12 @@ impl VariantDiv for Null {
 }
 
 impl VariantEq for Null {
-    fn eq(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::NULL(_) => Ok(true),
             _ => Ok(false),
@@ -81,7 +106,12 @@ impl VariantEq for Null {
 }
 
 impl VariantGe for Null {
-    fn ge(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::NULL(_) => Ok(true),
             _ => Ok(false),
@@ -90,7 +120,12 @@ impl VariantGe for Null {
 }
 
 impl VariantG for Null {
-    fn g(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
@@ -98,7 +133,12 @@ impl VariantG for Null {
 }
 
 impl VariantLe for Null {
-    fn le(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::NULL(_) => Ok(true),
             _ => Ok(false),
@@ -107,7 +147,12 @@ impl VariantLe for Null {
 }
 
 impl VariantL for Null {
-    fn l(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             _ => Ok(false),
         }
@@ -1,5 +1,16 @@
+use crate::interpreter::context::Context;
 use crate::{backtrace::Backtrace, mark::Mark};
 
 pub trait Represent {
     fn represent(&self, mark: Option<Mark>) -> Result<String, Backtrace>;
+
+    /// Represent with the context, where user-defined representations such as `__repr` of a
+    /// table may run.
+    fn represent_in(
+        &self,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<String, Backtrace> {
+        self.represent(mark)
+    }
 }
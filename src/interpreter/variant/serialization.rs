@@ -0,0 +1,670 @@
+use super::boolean::Boolean;
+use super::float::Float;
+use super::list::List;
+use super::null::Null;
+use super::represent::Represent;
+use super::strand::Strand;
+use super::table::Table;
+use super::Variant;
+use crate::backtrace::Backtrace;
+use crate::log::Log;
+use crate::raise_error;
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor,
+};
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+
+/// Convert a serializable Rust value into a variant.
+///
+/// Structs and maps become tables, sequences and tuples become lists, and enum variants with
+/// data become a table with the variant name as the only key.
+// Called by embedders, the binary never converts Rust values.
+#[allow(dead_code)]
+pub fn to_variant<T>(value: &T) -> Result<Variant, Backtrace>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(VariantSerializer)
+}
+
+/// Convert a variant into a deserializable Rust value, the reverse of [`to_variant`].
+#[allow(dead_code)]
+pub fn from_variant<T>(variant: Variant) -> Result<T, Backtrace>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(variant)
+}
+
+impl serde::ser::Error for Backtrace {
+    fn custom<T: Display>(message: T) -> Self {
+        Backtrace::new(Log::error(message.to_string(), None))
+    }
+}
+
+impl serde::de::Error for Backtrace {
+    fn custom<T: Display>(message: T) -> Self {
+        Backtrace::new(Log::error(message.to_string(), None))
+    }
+}
+
+impl Serialize for Variant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Variant::NULL(_) => serializer.serialize_unit(),
+            Variant::BOOL(boolean) => serializer.serialize_bool(boolean.is_true()),
+            Variant::INT(int) => serializer.serialize_i64((*int).into()),
+            Variant::FLOAT(float) => serializer.serialize_f64((*float).into()),
+            Variant::STRAND(strand) => serializer.serialize_str(strand.as_str()),
+            Variant::LIST(list) => {
+                let elements = list.to_vec(None).map_err(serde::ser::Error::custom)?;
+                let mut sequence = serializer.serialize_seq(Some(elements.len()))?;
+                for element in elements.iter() {
+                    sequence.serialize_element(element)?;
+                }
+                sequence.end()
+            }
+            Variant::TABLE(table) => {
+                let entries = table.entries(None).map_err(serde::ser::Error::custom)?;
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries.iter() {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            Variant::COMMAND(_) | Variant::CLOSURE(_) => Err(serde::ser::Error::custom(format!(
+                "`{}` cannot be serialized.",
+                self.represent(None).map_err(serde::ser::Error::custom)?
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VariantVisitor;
+
+        impl<'de> Visitor<'de> for VariantVisitor {
+            type Value = Variant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a value representable in Blinklet")
+            }
+
+            fn visit_unit<E>(self) -> Result<Variant, E> {
+                Ok(Variant::NULL(Null()))
+            }
+
+            fn visit_none<E>(self) -> Result<Variant, E> {
+                Ok(Variant::NULL(Null()))
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Variant, D::Error> {
+                Variant::deserialize(deserializer)
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Variant, E> {
+                Ok(Variant::from(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Variant, E> {
+                Ok(Variant::from(value))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Variant, E> {
+                Ok(Variant::from(value))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Variant, E> {
+                Ok(Variant::from(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Variant, E> {
+                Ok(Variant::from(value))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Variant, E> {
+                Ok(Variant::from(value))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<Variant, A::Error> {
+                let mut elements: Vec<Variant> = Vec::new();
+                while let Some(element) = access.next_element::<Variant>()? {
+                    elements.push(element);
+                }
+                Ok(Variant::LIST(List::from(elements)))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<Variant, A::Error> {
+                let mut entries: Vec<(String, Variant)> = Vec::new();
+                while let Some((key, value)) = access.next_entry::<String, Variant>()? {
+                    entries.push((key, value));
+                }
+                Ok(Variant::TABLE(Table::from(entries)))
+            }
+        }
+
+        deserializer.deserialize_any(VariantVisitor)
+    }
+}
+
+/// Serializer that produces [`Variant`], see [`to_variant`].
+pub struct VariantSerializer;
+
+pub struct ListSerializer {
+    name: Option<&'static str>,
+    elements: Vec<Variant>,
+}
+
+pub struct TableSerializer {
+    name: Option<&'static str>,
+    entries: Vec<(String, Variant)>,
+    key: Option<String>,
+}
+
+/// Wrap the value into a single entry table, used for enum variants with data.
+fn wrap_variant(name: &'static str, value: Variant) -> Variant {
+    Variant::TABLE(Table::from(vec![(String::from(name), value)]))
+}
+
+fn into_key(variant: Variant) -> Result<String, Backtrace> {
+    match variant {
+        Variant::STRAND(strand) => Ok(strand.into()),
+        Variant::INT(_) | Variant::FLOAT(_) | Variant::BOOL(_) => variant.represent(None),
+        _ => {
+            raise_error!(
+                None,
+                "`{}` cannot be used as a key of a table.",
+                variant.represent(None)?
+            );
+        }
+    }
+}
+
+impl Serializer for VariantSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = ListSerializer;
+    type SerializeTupleVariant = ListSerializer;
+    type SerializeMap = TableSerializer;
+    type SerializeStruct = TableSerializer;
+    type SerializeStructVariant = TableSerializer;
+
+    fn serialize_bool(self, value: bool) -> Result<Variant, Backtrace> {
+        Ok(Variant::BOOL(Boolean::from(value)))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Variant, Backtrace> {
+        // `Variant::from` falls back to a float, which would not deserialize back.
+        if i64::try_from(value).is_err() {
+            raise_error!(None, "`{}` does not fit in an integer.", value);
+        }
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Variant, Backtrace> {
+        Ok(Variant::FLOAT(Float::from(value)))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Variant, Backtrace> {
+        Ok(Variant::STRAND(Strand::from(value.to_string())))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Variant, Backtrace> {
+        Ok(Variant::STRAND(Strand::from(value)))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Variant, Backtrace> {
+        Ok(Variant::from(value.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Variant, Backtrace> {
+        Ok(Variant::NULL(Null()))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Variant, Backtrace> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Variant, Backtrace> {
+        Ok(Variant::NULL(Null()))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Variant, Backtrace> {
+        Ok(Variant::NULL(Null()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Variant, Backtrace> {
+        Ok(Variant::STRAND(Strand::from(variant)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Variant, Backtrace> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Variant, Backtrace> {
+        Ok(wrap_variant(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ListSerializer, Backtrace> {
+        Ok(ListSerializer {
+            name: None,
+            elements: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer, Backtrace> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<ListSerializer, Backtrace> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<ListSerializer, Backtrace> {
+        Ok(ListSerializer {
+            name: Some(variant),
+            elements: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<TableSerializer, Backtrace> {
+        Ok(TableSerializer {
+            name: None,
+            entries: Vec::new(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<TableSerializer, Backtrace> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<TableSerializer, Backtrace> {
+        Ok(TableSerializer {
+            name: Some(variant),
+            entries: Vec::new(),
+            key: None,
+        })
+    }
+}
+
+impl ListSerializer {
+    fn finish(self) -> Variant {
+        let list = Variant::LIST(List::from(self.elements));
+        match self.name {
+            Some(name) => wrap_variant(name, list),
+            None => list,
+        }
+    }
+}
+
+impl SerializeSeq for ListSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Backtrace> {
+        self.elements.push(to_variant(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Variant, Backtrace> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeTuple for ListSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Backtrace> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Variant, Backtrace> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeTupleStruct for ListSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Backtrace> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Variant, Backtrace> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeTupleVariant for ListSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Backtrace> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Variant, Backtrace> {
+        Ok(self.finish())
+    }
+}
+
+impl TableSerializer {
+    fn finish(self) -> Variant {
+        let table = Variant::TABLE(Table::from(self.entries));
+        match self.name {
+            Some(name) => wrap_variant(name, table),
+            None => table,
+        }
+    }
+}
+
+impl SerializeMap for TableSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Backtrace> {
+        self.key = Some(into_key(to_variant(key)?)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Backtrace> {
+        let key = match self.key.take() {
+            Some(key) => key,
+            None => {
+                raise_error!(None, "Value is serialized before its key.");
+            }
+        };
+        self.entries.push((key, to_variant(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Variant, Backtrace> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeStruct for TableSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Backtrace> {
+        self.entries.push((String::from(key), to_variant(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Variant, Backtrace> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeStructVariant for TableSerializer {
+    type Ok = Variant;
+    type Error = Backtrace;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Backtrace> {
+        SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Variant, Backtrace> {
+        Ok(self.finish())
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Backtrace> for Variant {
+    type Deserializer = Variant;
+
+    fn into_deserializer(self) -> Variant {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Variant {
+    type Error = Backtrace;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Backtrace> {
+        match self {
+            Variant::NULL(_) => visitor.visit_unit(),
+            Variant::BOOL(boolean) => visitor.visit_bool(boolean.is_true()),
+            Variant::INT(int) => visitor.visit_i64(int.into()),
+            Variant::FLOAT(float) => {
+                let float: f64 = float.into();
+                // Integral floats are handed out as integers so integer fields accept them.
+                if float.fract() == 0.0 && float >= i64::MIN as f64 && float <= i64::MAX as f64 {
+                    visitor.visit_i64(float as i64)
+                } else {
+                    visitor.visit_f64(float)
+                }
+            }
+            Variant::STRAND(strand) => visitor.visit_string(strand.into()),
+            Variant::LIST(list) => {
+                let mut sequence = SeqDeserializer::new(list.to_vec(None)?.into_iter());
+                let value = visitor.visit_seq(&mut sequence)?;
+                sequence.end()?;
+                Ok(value)
+            }
+            Variant::TABLE(table) => {
+                let mut map = MapDeserializer::new(table.entries(None)?.into_iter());
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Variant::COMMAND(_) | Variant::CLOSURE(_) => {
+                raise_error!(None, "`{}` cannot be deserialized.", self.represent(None)?);
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Backtrace> {
+        match self {
+            Variant::NULL(_) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Backtrace> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Backtrace> {
+        match self {
+            Variant::STRAND(strand) => visitor.visit_enum(EnumDeserializer {
+                name: strand.into(),
+                value: None,
+            }),
+            Variant::TABLE(ref table) => {
+                let mut entries = table.entries(None)?;
+                if entries.len() != 1 {
+                    raise_error!(
+                        None,
+                        "Expecting a table with a single entry as enum, but `{}` is given.",
+                        self.represent(None)?
+                    );
+                }
+                let (name, value) = entries.pop().unwrap();
+                visitor.visit_enum(EnumDeserializer {
+                    name,
+                    value: Some(value),
+                })
+            }
+            _ => {
+                raise_error!(
+                    None,
+                    "Expecting a string or a table as enum, but `{}` is given.",
+                    self.represent(None)?
+                );
+            }
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct EnumDeserializer {
+    name: String,
+    value: Option<Variant>,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer {
+    type Error = Backtrace;
+    type Variant = EnumValue;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, EnumValue), Backtrace> {
+        let name = seed.deserialize(IntoDeserializer::<Backtrace>::into_deserializer(self.name))?;
+        Ok((name, EnumValue(self.value)))
+    }
+}
+
+struct EnumValue(Option<Variant>);
+
+impl<'de> VariantAccess<'de> for EnumValue {
+    type Error = Backtrace;
+
+    fn unit_variant(self) -> Result<(), Backtrace> {
+        match self.0 {
+            None | Some(Variant::NULL(_)) => Ok(()),
+            Some(value) => {
+                raise_error!(
+                    None,
+                    "Expecting no data for unit variant, but `{}` is given.",
+                    value.represent(None)?
+                );
+            }
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Backtrace> {
+        seed.deserialize(self.0.unwrap_or(Variant::NULL(Null())))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Backtrace> {
+        self.0
+            .unwrap_or(Variant::NULL(Null()))
+            .deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Backtrace> {
+        self.0
+            .unwrap_or(Variant::NULL(Null()))
+            .deserialize_any(visitor)
+    }
+}
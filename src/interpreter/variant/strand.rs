@@ -1,3 +1,6 @@
+use super::int::Int;
+use super::list::List;
+use super::null::Null;
 use super::variant_ops::{
     VariantAdd, VariantDiv, VariantDuplicate, VariantEq, VariantG, VariantGe, VariantL, VariantLe,
     VariantMul, VariantSub,
@@ -12,7 +15,12 @@ use std::fmt::Debug;
 pub struct Strand(String);
 
 impl VariantAdd for Strand {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => Ok(Variant::STRAND(Strand::from(
                 self.0.clone() + rhs.represent(mark)?.as_str(),
@@ -22,7 +30,12 @@ impl VariantAdd for Strand {
 }
 
 impl VariantSub for Strand {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -37,7 +50,12 @@ impl VariantSub for Strand {
 }
 
 impl VariantMul for Strand {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -52,7 +70,12 @@ impl VariantMul for Strand {
 }
 
 impl VariantDiv for Strand {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
         match rhs {
             _ => {
                 raise_error!(
@@ -67,7 +90,12 @@ impl VariantDiv for Strand {
 }
 
 impl VariantEq for Strand {
-    fn eq(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
             Variant::STRAND(strand) => Ok(self.0 == strand.0),
             _ => Ok(false),
@@ -76,34 +104,56 @@ impl VariantEq for Strand {
 }
 
 impl VariantGe for Strand {
-    fn ge(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
-            Variant::STRAND(strand) => Ok(self.0 == strand.0),
+            Variant::STRAND(strand) => Ok(self.0 >= strand.0),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantG for Strand {
-    fn g(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
+            Variant::STRAND(strand) => Ok(self.0 > strand.0),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantLe for Strand {
-    fn le(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
-            Variant::STRAND(strand) => Ok(self.0 == strand.0),
+            Variant::STRAND(strand) => Ok(self.0 <= strand.0),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantL for Strand {
-    fn l(&self, rhs: &Variant, _mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        _mark: Option<Mark>,
+        _context: &mut Context,
+    ) -> Result<bool, Backtrace> {
         match rhs {
+            Variant::STRAND(strand) => Ok(self.0 < strand.0),
             _ => Ok(false),
         }
     }
@@ -149,4 +199,53 @@ impl Strand {
     pub fn as_str<'a>(&'a self) -> &'a str {
         self.0.as_str()
     }
+
+    /// Count of characters, which is not the count of bytes for non-ASCII text.
+    pub fn length(&self) -> Variant {
+        Variant::INT(Int::from(self.0.chars().count() as i64))
+    }
+
+    /// Byte offset of the character at `index`, negative index counts from the end.
+    fn char_offset(&self, index: Int, mark: Option<Mark>) -> Result<usize, Backtrace> {
+        let position = index.as_bound(self.0.chars().count(), mark)?;
+        Ok(self
+            .0
+            .char_indices()
+            .nth(position)
+            .map_or(self.0.len(), |(offset, _)| offset))
+    }
+
+    pub fn slice(
+        &self,
+        start: Int,
+        end: Option<Int>,
+        mark: Option<Mark>,
+    ) -> Result<Variant, Backtrace> {
+        let start = self.char_offset(start, mark.clone())?;
+        let end = match end {
+            Some(end) => self.char_offset(end, mark.clone())?,
+            None => self.0.len(),
+        };
+        if start > end {
+            raise_error!(mark, "Slice starts after it ends.");
+        }
+        Ok(Variant::STRAND(Strand::from(&self.0[start..end])))
+    }
+
+    /// Character index of the first occurrence of `pattern`, or null if not found.
+    pub fn find(&self, pattern: &str) -> Variant {
+        match self.0.find(pattern) {
+            Some(offset) => Variant::INT(Int::from(self.0[..offset].chars().count() as i64)),
+            None => Variant::NULL(Null()),
+        }
+    }
+
+    pub fn chars(&self) -> Variant {
+        Variant::LIST(List::from(
+            self.0
+                .chars()
+                .map(|character| Variant::STRAND(Strand::from(character.to_string())))
+                .collect::<Vec<Variant>>(),
+        ))
+    }
 }
@@ -5,9 +5,15 @@ use super::variant_ops::{
 };
 use crate::backtrace::Backtrace;
 use crate::interpreter::context::Context;
+use crate::interpreter::native::NativeFn;
+use crate::interpreter::variant::command::Command;
+use crate::interpreter::variant::int::Int;
+use crate::interpreter::variant::list::List;
+use crate::interpreter::variant::strand::Strand;
 use crate::interpreter::variant::Variant;
 use crate::mark::Mark;
 use crate::{mutex_lock_unwrap, raise_error};
+use indexmap::IndexMap;
 use std::collections::HashMap;
 use std::{
     fmt::Debug,
@@ -15,16 +21,31 @@ use std::{
 };
 
 #[derive(Clone)]
-pub struct Table(Arc<Mutex<HashMap<String, Variant>>>);
+/// Entries keep the order they are inserted in, so iterating a table is deterministic.
+///
+/// A table may delegate to a prototype table, identifiers missing from the table are then looked
+/// up along the prototype chain.
+pub struct Table {
+    entries: Arc<Mutex<IndexMap<String, Variant>>>,
+    prototype: Arc<Mutex<Option<Table>>>,
+}
 
 impl Default for Table {
     fn default() -> Self {
-        Table(Arc::new(Mutex::new(HashMap::default())))
+        Table::from(IndexMap::default())
     }
 }
 
 impl VariantAdd for Table {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        if let Some(result) = self.call_operator("__add", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             _ => {
                 raise_error!(
@@ -39,7 +60,15 @@ impl VariantAdd for Table {
 }
 
 impl VariantSub for Table {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        if let Some(result) = self.call_operator("__sub", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             _ => {
                 raise_error!(
@@ -54,7 +83,15 @@ impl VariantSub for Table {
 }
 
 impl VariantMul for Table {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        if let Some(result) = self.call_operator("__mul", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             _ => {
                 raise_error!(
@@ -69,7 +106,15 @@ impl VariantMul for Table {
 }
 
 impl VariantDiv for Table {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace> {
+        if let Some(result) = self.call_operator("__div", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             _ => {
                 raise_error!(
@@ -84,37 +129,37 @@ impl VariantDiv for Table {
 }
 
 impl VariantEq for Table {
-    fn eq(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn eq(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        if let Some(result) = self.call_comparison("__eq", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
-            Variant::TABLE(table) => self.is_table_eq(table, mark),
+            Variant::TABLE(table) => self.is_table_eq(table, mark, context),
             _ => Ok(false),
         }
     }
 }
 
 impl VariantGe for Table {
-    fn ge(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn ge(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        if let Some(result) = self.call_comparison("__ge", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             Variant::TABLE(table) => {
-                let other_guard = mutex_lock_unwrap!(table.0, mark);
-                let self_guard = mutex_lock_unwrap!(self.0, mark);
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for key in other_guard.keys() {
-                    let self_element = self_guard.get(key);
-                    if self_element.is_none() {
-                        return Ok(false);
-                    }
-                    let self_element = self_element.unwrap();
-                    let table_element = other_guard.get(key).unwrap();
-                    if !self_element.ge(table_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-                Ok(true)
+                self.compare_entries(table, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.ge(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -122,28 +167,20 @@ impl VariantGe for Table {
 }
 
 impl VariantG for Table {
-    fn g(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn g(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        if let Some(result) = self.call_comparison("__gt", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             Variant::TABLE(table) => {
-                let other_guard = mutex_lock_unwrap!(table.0, mark);
-                let self_guard = mutex_lock_unwrap!(self.0, mark);
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for key in other_guard.keys() {
-                    let self_element = self_guard.get(key);
-                    if self_element.is_none() {
-                        return Ok(false);
-                    }
-                    let self_element = self_element.unwrap();
-                    let table_element = other_guard.get(key).unwrap();
-                    if !self_element.g(table_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-                Ok(true)
+                self.compare_entries(table, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.g(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -151,28 +188,20 @@ impl VariantG for Table {
 }
 
 impl VariantLe for Table {
-    fn le(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn le(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        if let Some(result) = self.call_comparison("__le", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             Variant::TABLE(table) => {
-                let other_guard = mutex_lock_unwrap!(table.0, mark);
-                let self_guard = mutex_lock_unwrap!(self.0, mark);
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for key in other_guard.keys() {
-                    let self_element = self_guard.get(key);
-                    if self_element.is_none() {
-                        return Ok(false);
-                    }
-                    let self_element = self_element.unwrap();
-                    let table_element = other_guard.get(key).unwrap();
-                    if !self_element.le(table_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-                Ok(true)
+                self.compare_entries(table, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.le(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -180,28 +209,20 @@ impl VariantLe for Table {
 }
 
 impl VariantL for Table {
-    fn l(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace> {
+    fn l(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        if let Some(result) = self.call_comparison("__lt", rhs, mark.clone(), context)? {
+            return Ok(result);
+        }
         match rhs {
             Variant::TABLE(table) => {
-                let other_guard = mutex_lock_unwrap!(table.0, mark);
-                let self_guard = mutex_lock_unwrap!(self.0, mark);
-
-                if self_guard.len() != other_guard.len() {
-                    return Ok(false);
-                }
-
-                for key in other_guard.keys() {
-                    let self_element = self_guard.get(key);
-                    if self_element.is_none() {
-                        return Ok(false);
-                    }
-                    let self_element = self_element.unwrap();
-                    let table_element = other_guard.get(key).unwrap();
-                    if !self_element.l(table_element, mark.clone())? {
-                        return Ok(false);
-                    }
-                }
-                Ok(true)
+                self.compare_entries(table, mark.clone(), context, |lhs, rhs, context| {
+                    lhs.l(rhs, mark.clone(), context)
+                })
             }
             _ => Ok(false),
         }
@@ -210,11 +231,12 @@ impl VariantL for Table {
 
 impl VariantDuplicate for Table {
     fn duplicate(&self, mark: Option<Mark>, context: &mut Context) -> Result<Variant, Backtrace> {
-        let guard = mutex_lock_unwrap!(self.0, mark);
+        let guard = mutex_lock_unwrap!(self.entries, mark);
         let data: Table = Table::default();
+        data.set_prototype(self.prototype(mark.clone())?, mark.clone())?;
         context.scopes.push(data.clone());
         {
-            let mut data_guard = mutex_lock_unwrap!(data.0, mark);
+            let mut data_guard = mutex_lock_unwrap!(data.entries, mark);
             for (key, value) in guard.iter() {
                 let duplicated = value.duplicate(mark.clone(), context)?;
                 data_guard.insert(key.clone(), duplicated);
@@ -233,21 +255,49 @@ impl Debug for Table {
 
 impl Represent for Table {
     fn represent(&self, mark: Option<Mark>) -> Result<String, Backtrace> {
-        let guard = mutex_lock_unwrap!(self.0, mark.clone());
-        let representations = guard
-            .iter()
-            .map(|(key, variant)| match variant {
-                Variant::STRAND(strand) => Ok(format!("{}: \"{}\"", key, strand.as_str())),
-                _ => Ok(format!("{}: {}", key, variant.represent(mark.clone())?)),
-            })
-            .collect::<Result<Vec<String>, Backtrace>>()?;
-        Ok(format!("<Table {{{}}}>", representations.join(", ")))
+        Table::format_entries(self.entries(mark.clone())?, |variant| {
+            variant.represent(mark.clone())
+        })
+    }
+
+    fn represent_in(&self, mark: Option<Mark>, context: &mut Context) -> Result<String, Backtrace> {
+        let is_representing = context.representing.iter().any(|table| table.is_same(self));
+        if !is_representing {
+            context.representing.push(self.clone());
+            let result = self.call_special("__repr", Vec::new(), mark.clone(), context);
+            context.representing.pop();
+            match result? {
+                Some(Variant::STRAND(strand)) => return Ok(String::from(strand.as_str())),
+                Some(_) => {
+                    raise_error!(mark, "`__repr` of the table must return a string.");
+                }
+                None => {}
+            }
+        }
+        Table::format_entries(self.entries(mark.clone())?, |variant| {
+            variant.represent_in(mark.clone(), context)
+        })
+    }
+}
+
+impl From<IndexMap<String, Variant>> for Table {
+    fn from(value: IndexMap<String, Variant>) -> Self {
+        Table {
+            entries: Arc::new(Mutex::new(value)),
+            prototype: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl From<Vec<(String, Variant)>> for Table {
+    fn from(value: Vec<(String, Variant)>) -> Self {
+        Table::from(value.into_iter().collect::<IndexMap<String, Variant>>())
     }
 }
 
 impl From<HashMap<String, Variant>> for Table {
     fn from(value: HashMap<String, Variant>) -> Self {
-        Table(Arc::new(Mutex::new(value)))
+        Table::from(value.into_iter().collect::<IndexMap<String, Variant>>())
     }
 }
 
@@ -258,12 +308,25 @@ impl Table {
         value: Variant,
         mark: Option<Mark>,
     ) -> Result<Option<Variant>, Backtrace> {
-        let mut guard = mutex_lock_unwrap!(self.0, mark);
+        let mut guard = mutex_lock_unwrap!(self.entries, mark);
         Ok(guard.insert(key, value))
     }
 
+    /// Insert a typed Rust function as a command, building a Rust-backed module.
+    pub fn register_fn<A, T>(&mut self, key: &str, function: T) -> Result<(), Backtrace>
+    where
+        T: NativeFn<A>,
+    {
+        self.insert(
+            String::from(key),
+            Variant::COMMAND(Command::native(function)),
+            None,
+        )?;
+        Ok(())
+    }
+
     pub fn get(&self, key: &String, mark: Option<Mark>) -> Result<Option<Variant>, Backtrace> {
-        let guard = mutex_lock_unwrap!(self.0, mark);
+        let guard = mutex_lock_unwrap!(self.entries, mark);
         let variant = guard.get(key);
         Ok(if variant.is_none() {
             None
@@ -272,27 +335,260 @@ impl Table {
         })
     }
 
+    /// Get the value of the key from the table, or from its prototype chain if it is missing.
+    pub fn lookup(&self, key: &String, mark: Option<Mark>) -> Result<Option<Variant>, Backtrace> {
+        if let Some(value) = self.get(key, mark.clone())? {
+            return Ok(Some(value));
+        }
+        let mut current = self.prototype(mark.clone())?;
+        if current.is_none() {
+            return Ok(None);
+        }
+        let mut visited: Vec<Table> = vec![self.clone()];
+        while let Some(table) = current {
+            if visited.iter().any(|visited| visited.is_same(&table)) {
+                raise_error!(mark, "Cycle found in the prototype chain.");
+            }
+            if let Some(value) = table.get(key, mark.clone())? {
+                return Ok(Some(value));
+            }
+            current = table.prototype(mark.clone())?;
+            visited.push(table);
+        }
+        Ok(None)
+    }
+
+    /// Whether the key is in the table or in its prototype chain.
+    pub fn contains_key_deep(&self, key: &String, mark: Option<Mark>) -> Result<bool, Backtrace> {
+        Ok(self.lookup(key, mark)?.is_some())
+    }
+
+    /// Whether a lookup of the key may stop at the table, i.e. the key is in the table or the
+    /// table has a prototype chain that may have it.
+    pub fn may_contain_deep(&self, key: &str, mark: Option<Mark>) -> Result<bool, Backtrace> {
+        if mutex_lock_unwrap!(self.entries, mark).contains_key(key) {
+            return Ok(true);
+        }
+        Ok(mutex_lock_unwrap!(self.prototype, mark).is_some())
+    }
+
+    /// Position of the key in the order of the entries, without the prototype chain.
+    pub fn index_of(&self, key: &str, mark: Option<Mark>) -> Result<Option<usize>, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(guard.get_index_of(key))
+    }
+
+    /// Get the value of the entry at `index`, if the entry is of the key.
+    pub fn get_at(
+        &self,
+        index: usize,
+        key: &str,
+        mark: Option<Mark>,
+    ) -> Result<Option<Variant>, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(match guard.get_index(index) {
+            Some((entry_key, value)) if entry_key == key => Some(value.clone()),
+            _ => None,
+        })
+    }
+
+    /// Replace the value of the entry at `index` if the entry is of the key, otherwise give the
+    /// value back.
+    pub fn set_at(
+        &mut self,
+        index: usize,
+        key: &str,
+        value: Variant,
+        mark: Option<Mark>,
+    ) -> Result<Option<Variant>, Backtrace> {
+        let mut guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(match guard.get_index_mut(index) {
+            Some((entry_key, entry_value)) if entry_key == key => {
+                *entry_value = value;
+                None
+            }
+            _ => Some(value),
+        })
+    }
+
+    pub fn prototype(&self, mark: Option<Mark>) -> Result<Option<Table>, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.prototype, mark);
+        Ok(guard.clone())
+    }
+
+    /// Link the table to a prototype, refusing the link if the table is in the prototype chain.
+    pub fn set_prototype(
+        &self,
+        prototype: Option<Table>,
+        mark: Option<Mark>,
+    ) -> Result<(), Backtrace> {
+        let mut current = prototype.clone();
+        while let Some(table) = current {
+            if table.is_same(self) {
+                raise_error!(mark, "Prototype would make a cycle in the prototype chain.");
+            }
+            current = table.prototype(mark.clone())?;
+        }
+        let mut guard = mutex_lock_unwrap!(self.prototype, mark);
+        *guard = prototype;
+        Ok(())
+    }
+
+    /// Whether both are the same table rather than equal tables.
+    pub fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.entries, &other.entries)
+    }
+
+    /// Call the special closure member `name` with the table and `arguments`, if the table or its
+    /// prototype chain has it.
+    fn call_special(
+        &self,
+        name: &str,
+        mut arguments: Vec<Variant>,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Option<Variant>, Backtrace> {
+        let mut method = match self.lookup(&String::from(name), mark.clone())? {
+            Some(Variant::CLOSURE(method)) => method,
+            Some(_) => {
+                raise_error!(mark, "`{}` of the table must be a closure.", name);
+            }
+            None => return Ok(None),
+        };
+        arguments.insert(0, Variant::TABLE(self.clone()));
+        let mark = mark.unwrap_or(method.mark.clone());
+        Ok(Some(method.call_back(context, arguments, mark)?))
+    }
+
+    fn call_operator(
+        &self,
+        name: &str,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Option<Variant>, Backtrace> {
+        self.call_special(name, vec![rhs.clone()], mark, context)
+    }
+
+    fn call_comparison(
+        &self,
+        name: &str,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Option<bool>, Backtrace> {
+        match self.call_operator(name, rhs, mark.clone(), context)? {
+            Some(Variant::BOOL(boolean)) => Ok(Some(boolean.is_true())),
+            Some(_) => {
+                raise_error!(mark, "`{}` of the table must return a boolean.", name);
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn entries(&self, mark: Option<Mark>) -> Result<Vec<(String, Variant)>, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(guard
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+
     pub fn contains_key(&self, key: &String, mark: Option<Mark>) -> Result<bool, Backtrace> {
-        let guard = mutex_lock_unwrap!(self.0, mark);
+        let guard = mutex_lock_unwrap!(self.entries, mark);
         Ok(guard.contains_key(key))
     }
 
-    pub fn is_table_eq(&self, other: &Self, mark: Option<Mark>) -> Result<bool, Backtrace> {
-        let other_guard = mutex_lock_unwrap!(other.0, mark);
-        let self_guard = mutex_lock_unwrap!(self.0, mark);
+    /// Remove the entry, keeping the order of the rest.
+    pub fn remove(
+        &mut self,
+        key: &String,
+        mark: Option<Mark>,
+    ) -> Result<Option<Variant>, Backtrace> {
+        let mut guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(guard.shift_remove(key))
+    }
+
+    pub fn length(&self, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(Variant::INT(Int::from(guard.len() as i64)))
+    }
+
+    pub fn keys(&self, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(Variant::LIST(List::from(
+            guard
+                .keys()
+                .map(|key| Variant::STRAND(Strand::from(key.as_str())))
+                .collect::<Vec<Variant>>(),
+        )))
+    }
+
+    pub fn values(&self, mark: Option<Mark>) -> Result<Variant, Backtrace> {
+        let guard = mutex_lock_unwrap!(self.entries, mark);
+        Ok(Variant::LIST(List::from(
+            guard.values().cloned().collect::<Vec<Variant>>(),
+        )))
+    }
+
+    pub fn is_table_eq(
+        &self,
+        other: &Self,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace> {
+        // A table is equal to itself, even when it holds closures that capture it.
+        if self.is_same(other) {
+            return Ok(true);
+        }
+        self.compare_entries(other, mark.clone(), context, |lhs, rhs, context| {
+            lhs.eq(rhs, mark.clone(), context)
+        })
+    }
 
-        if self_guard.len() != other_guard.len() {
+    fn format_entries<F>(
+        entries: Vec<(String, Variant)>,
+        mut represent: F,
+    ) -> Result<String, Backtrace>
+    where
+        F: FnMut(&Variant) -> Result<String, Backtrace>,
+    {
+        let representations = entries
+            .iter()
+            .map(|(key, variant)| match variant {
+                Variant::STRAND(strand) => Ok(format!("{}: \"{}\"", key, strand.as_str())),
+                _ => Ok(format!("{}: {}", key, represent(variant)?)),
+            })
+            .collect::<Result<Vec<String>, Backtrace>>()?;
+        Ok(format!("<Table {{{}}}>", representations.join(", ")))
+    }
+
+    /// Whether both tables have the same keys in the same order and `compare` holds for the
+    /// values of each key.
+    ///
+    /// Entries are compared in insertion order on copies, so a table compared with itself or
+    /// holding itself is not locked twice.
+    fn compare_entries<F>(
+        &self,
+        other: &Self,
+        mark: Option<Mark>,
+        context: &mut Context,
+        mut compare: F,
+    ) -> Result<bool, Backtrace>
+    where
+        F: FnMut(&Variant, &Variant, &mut Context) -> Result<bool, Backtrace>,
+    {
+        let self_entries = self.entries(mark.clone())?;
+        let other_entries = other.entries(mark.clone())?;
+
+        if self_entries.len() != other_entries.len() {
             return Ok(false);
         }
 
-        for key in other_guard.keys() {
-            let self_element = self_guard.get(key);
-            if self_element.is_none() {
-                return Ok(false);
-            }
-            let self_element = self_element.unwrap();
-            let table_element = other_guard.get(key).unwrap();
-            if !self_element.eq(table_element, mark.clone())? {
+        for ((self_key, self_element), (other_key, other_element)) in
+            self_entries.iter().zip(other_entries.iter())
+        {
+            if self_key != other_key || !compare(self_element, other_element, context)? {
                 return Ok(false);
             }
         }
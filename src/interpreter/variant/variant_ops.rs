@@ -4,39 +4,84 @@ use crate::interpreter::context::Context;
 use crate::mark::Mark;
 
 pub trait VariantAdd {
-    fn add(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace>;
+    fn add(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace>;
 }
 
 pub trait VariantSub {
-    fn sub(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace>;
+    fn sub(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace>;
 }
 
 pub trait VariantMul {
-    fn mul(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace>;
+    fn mul(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace>;
 }
 
 pub trait VariantDiv {
-    fn div(&self, rhs: &Variant, mark: Option<Mark>) -> Result<Variant, Backtrace>;
+    fn div(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<Variant, Backtrace>;
 }
 
 pub trait VariantEq {
-    fn eq(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace>;
+    fn eq(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace>;
 }
 
 pub trait VariantGe {
-    fn ge(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace>;
+    fn ge(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace>;
 }
 
 pub trait VariantG {
-    fn g(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace>;
+    fn g(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace>;
 }
 
 pub trait VariantLe {
-    fn le(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace>;
+    fn le(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace>;
 }
 
 pub trait VariantL {
-    fn l(&self, rhs: &Variant, mark: Option<Mark>) -> Result<bool, Backtrace>;
+    fn l(
+        &self,
+        rhs: &Variant,
+        mark: Option<Mark>,
+        context: &mut Context,
+    ) -> Result<bool, Backtrace>;
 }
 
 pub trait VariantDuplicate {
@@ -4,6 +4,8 @@ use std::fmt::Display;
 #[derive(Debug, Clone)]
 pub enum LogMessage {
     TRACE,
+    /// Remark on an error, pointing at a related place.
+    NOTE(String),
     ERROR(String),
     BUG(String),
 }
@@ -18,6 +20,7 @@ impl Display for Log {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let header = match self.message {
             LogMessage::TRACE => format!("Traceback: \n"),
+            LogMessage::NOTE(ref note) => format!("Note: {}\n", note.clone()),
             LogMessage::ERROR(ref error) => format!("Error: {}\n", error.clone()),
             LogMessage::BUG(ref bug) => format!(
                 "Internal Bug: {} (Please report to https://github.com/RechieKho/blinklet/issues/new)\n",
@@ -42,6 +45,13 @@ impl Log {
         }
     }
 
+    pub fn note(message: String, mark: Option<Mark>) -> Log {
+        Log {
+            message: LogMessage::NOTE(message),
+            mark,
+        }
+    }
+
     pub fn error(message: String, mark: Option<Mark>) -> Log {
         Log {
             message: LogMessage::ERROR(message),
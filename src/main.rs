@@ -5,12 +5,18 @@ mod interpreter;
 mod log;
 mod mark;
 mod parser;
+mod repl;
 
+use interpreter::capability::Capabilities;
 use interpreter::context::Context;
-use interpreter::resource::ResourcePath;
+use interpreter::resource::archive_resource::ArchiveResource;
+use interpreter::resource::chain_resource::ChainResource;
+use interpreter::resource::system_resource::SystemResource;
+use interpreter::resource::{Resource, ResourcePath, SEPERATOR_STR};
 use interpreter::variant::strand::Strand;
 use interpreter::variant::Variant;
 use std::env;
+use std::fs::canonicalize;
 use std::path::PathBuf;
 
 macro_rules! print_error {
@@ -20,39 +26,164 @@ macro_rules! print_error {
     };
 }
 
+/// Flag adding a directory to search imported scripts in, as `-I <directory>` or `-I<directory>`.
+const INCLUDE_FLAG_STR: &str = "-I";
+/// Flag running the script with [`Capabilities::sandbox`], imports are then only allowed from the
+/// directory of the script and the directories given by [`INCLUDE_FLAG_STR`].
+const SANDBOX_FLAG_STR: &str = "--sandbox";
+/// Flag allowing `console` to run a program in the sandbox, as `--allow-console <program>`.
+const ALLOW_CONSOLE_FLAG_STR: &str = "--allow-console";
+/// Flag allowing file access in the sandbox.
+const ALLOW_FILE_FLAG_STR: &str = "--allow-file";
+/// Flag allowing network access in the sandbox.
+const ALLOW_NETWORK_FLAG_STR: &str = "--allow-network";
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 1 {
-        eprintln!(
-            "usage: {} {{script_path}} [script_arguments...]",
-            args.first().unwrap()
-        );
-        return;
+    let mut args = env::args().skip(1).peekable();
+
+    // Search roots given by flags come before those in the environment.
+    let mut resource = SystemResource::default();
+    let mut is_sandboxed = false;
+    let mut capabilities = Capabilities::sandbox();
+    let mut allow_flags: Vec<String> = Vec::new();
+    while let Some(arg) = args.next_if(|arg| arg.starts_with('-')) {
+        if arg == SANDBOX_FLAG_STR {
+            is_sandboxed = true;
+            continue;
+        }
+        if arg == ALLOW_CONSOLE_FLAG_STR {
+            match args.next() {
+                Some(program) => capabilities.console.get_or_insert_with(Vec::new).push(program),
+                None => {
+                    eprintln!("Expecting a program after '{}'.", ALLOW_CONSOLE_FLAG_STR);
+                    return;
+                }
+            }
+            allow_flags.push(arg);
+            continue;
+        }
+        if arg == ALLOW_FILE_FLAG_STR || arg == ALLOW_NETWORK_FLAG_STR {
+            capabilities.file |= arg == ALLOW_FILE_FLAG_STR;
+            capabilities.network |= arg == ALLOW_NETWORK_FLAG_STR;
+            allow_flags.push(arg);
+            continue;
+        }
+        if !arg.starts_with(INCLUDE_FLAG_STR) {
+            eprintln!("Unknown flag '{}'.", arg);
+            return;
+        }
+        let root = if arg == INCLUDE_FLAG_STR {
+            match args.next() {
+                Some(root) => root,
+                None => {
+                    eprintln!("Expecting a directory after '{}'.", INCLUDE_FLAG_STR);
+                    return;
+                }
+            }
+        } else {
+            String::from(&arg[INCLUDE_FLAG_STR.len()..])
+        };
+        if let Err(error) = resource.add_root(PathBuf::from(root)) {
+            print_error!(error);
+            return;
+        }
+    }
+    if !is_sandboxed {
+        if let Some(flag) = allow_flags.first() {
+            eprintln!("'{}' is only meaningful with '{}'.", flag, SANDBOX_FLAG_STR);
+            return;
+        }
     }
 
-    let path = match ResourcePath::try_from(PathBuf::from(&args[1])) {
-        Ok(path) => path,
+    // The environment is not trusted in the sandbox, only the given roots are searched.
+    if !is_sandboxed {
+        if let Err(error) = resource.add_roots_from_env() {
+            print_error!(error);
+            return;
+        }
+    }
+    let mut import_roots: Vec<ResourcePath> = resource.roots().to_vec();
+
+    let mut context = match Context::new() {
+        Ok(context) => context,
         Err(error) => {
             print_error!(error);
             return;
         }
     };
+    let script = match args.next() {
+        Some(script) => script,
+        None => {
+            if is_sandboxed {
+                if let Ok(directory) = env::current_dir().and_then(canonicalize) {
+                    match ResourcePath::try_from(directory) {
+                        Ok(directory) => import_roots.push(directory),
+                        Err(error) => {
+                            print_error!(error);
+                            return;
+                        }
+                    }
+                }
+                capabilities.import_roots = Some(import_roots);
+                context.capabilities = capabilities;
+            }
+            context.resource = Box::new(resource);
+            repl::run_repl(&mut context);
+            return;
+        }
+    };
 
-    let script_args = &args[2..];
-    let context = Context::new();
-
-    let mut context = match context {
-        Ok(context) => context,
+    // `<archive>::<entry>` runs the entry module inside the archive, other imports fall back to
+    // the filesystem.
+    let archive = script
+        .split_once(SEPERATOR_STR)
+        .filter(|(archive, _)| PathBuf::from(archive).is_file());
+    let path = match archive {
+        Some((archive, entry)) => {
+            let archive = match ArchiveResource::open(&PathBuf::from(archive)) {
+                Ok(archive) => archive,
+                Err(error) => {
+                    print_error!(error);
+                    return;
+                }
+            };
+            import_roots.push(archive.root().clone());
+            let resources: Vec<Box<dyn Resource>> = vec![Box::new(archive), Box::new(resource)];
+            context.resource = Box::new(ChainResource::from(resources));
+            ResourcePath::try_from(String::from(entry))
+        }
+        None => {
+            context.resource = Box::new(resource);
+            // Imports are checked by their real path, so the directory of the script is allowed by
+            // its real path too. The script keeps the path it is given by for its module name.
+            let script = PathBuf::from(&script);
+            if is_sandboxed {
+                let real = canonicalize(&script).unwrap_or(script.clone());
+                match ResourcePath::try_from(real) {
+                    Ok(mut real) => import_roots.push(real.remove_parent_path()),
+                    Err(error) => {
+                        print_error!(error);
+                        return;
+                    }
+                }
+            }
+            ResourcePath::try_from(script)
+        }
+    };
+    let path = match path {
+        Ok(path) => path,
         Err(error) => {
             print_error!(error);
             return;
         }
     };
+    if is_sandboxed {
+        capabilities.import_roots = Some(import_roots);
+        context.capabilities = capabilities;
+    }
 
-    for arg in script_args.iter() {
-        context
-            .slots
-            .push(Variant::STRAND(Strand::from(arg.clone())));
+    for arg in args {
+        context.slots.push(Variant::STRAND(Strand::from(arg)));
     }
 
     let _ = match context.run_resource(path) {
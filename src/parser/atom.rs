@@ -5,10 +5,17 @@ use crate::backtrace::Backtrace;
 use crate::mark::Mark;
 use crate::raise_bug;
 use crate::raise_error;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 const NULL_STR: &'static str = "null";
 const TRUE_STR: &'static str = "true";
 const FALSE_STR: &'static str = "false";
+pub const IF_STR: &str = "if";
+pub const ELIF_STR: &str = "elif";
+pub const ELSE_STR: &str = "else";
+
+/// Source of the identities of atoms, unique across every parsed code.
+static NEXT_ATOM_ID: AtomicUsize = AtomicUsize::new(0);
 
 #[macro_export]
 macro_rules! atom_as_identifier {
@@ -38,6 +45,7 @@ pub enum AtomValue {
     IDENTIFIER(String),
     BOOL(bool),
     STRING(String),
+    INT(i64),
     FLOAT(f64),
     STATEMENT(Vec<Atom>),
 }
@@ -46,6 +54,7 @@ pub enum AtomValue {
 pub struct Atom {
     pub value: AtomValue,
     pub mark: Mark,
+    id: usize,
 }
 
 impl Atom {
@@ -53,6 +62,7 @@ impl Atom {
         Atom {
             value: AtomValue::NULL,
             mark,
+            id: NEXT_ATOM_ID.fetch_add(1, Ordering::Relaxed),
         }
     }
 
@@ -60,6 +70,7 @@ impl Atom {
         Atom {
             value: AtomValue::IDENTIFIER(identifier),
             mark,
+            id: NEXT_ATOM_ID.fetch_add(1, Ordering::Relaxed),
         }
     }
 
@@ -67,6 +78,7 @@ impl Atom {
         Atom {
             value: AtomValue::BOOL(boolean),
             mark,
+            id: NEXT_ATOM_ID.fetch_add(1, Ordering::Relaxed),
         }
     }
 
@@ -74,6 +86,15 @@ impl Atom {
         Atom {
             value: AtomValue::STRING(string),
             mark,
+            id: NEXT_ATOM_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    pub fn new_int(int: i64, mark: Mark) -> Self {
+        Atom {
+            value: AtomValue::INT(int),
+            mark,
+            id: NEXT_ATOM_ID.fetch_add(1, Ordering::Relaxed),
         }
     }
 
@@ -81,6 +102,7 @@ impl Atom {
         Atom {
             value: AtomValue::FLOAT(float),
             mark,
+            id: NEXT_ATOM_ID.fetch_add(1, Ordering::Relaxed),
         }
     }
 
@@ -88,9 +110,15 @@ impl Atom {
         Atom {
             value: AtomValue::STATEMENT(statement),
             mark,
+            id: NEXT_ATOM_ID.fetch_add(1, Ordering::Relaxed),
         }
     }
 
+    /// Identity of the atom, kept by its clones, e.g. to cache what is derived from the atom.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
     pub fn from_token(token: Token) -> Self {
         let Token { value, mark } = token;
         match value {
@@ -106,6 +134,7 @@ impl Atom {
                 }
             }
             TokenValue::STRING(string) => Atom::new_string(string, mark),
+            TokenValue::INT(int) => Atom::new_int(int, mark),
             TokenValue::FLOAT(float) => Atom::new_float(float, mark),
         }
     }
@@ -202,6 +231,12 @@ pub fn generate_statements(mut lot: Vec<TokenLine>) -> Result<Vec<Atom>, Backtra
                         "String as the head of a statement is forbidden."
                     );
                 }
+                AtomValue::INT(_) => {
+                    raise_error!(
+                        Some(first_atom.mark.clone()),
+                        "INT as the head of a statement is forbidden."
+                    );
+                }
                 AtomValue::FLOAT(_) => {
                     raise_error!(
                         Some(first_atom.mark.clone()),
@@ -238,5 +273,70 @@ pub fn generate_statements(mut lot: Vec<TokenLine>) -> Result<Vec<Atom>, Backtra
         current_indent_count = token_line.indent_count;
     }
 
+    fold_clauses(&mut result)?;
     Ok(result)
 }
+
+/// Get the clause keyword heading the atom, if the atom is an `elif` or `else` statement.
+/// These statements are folded into the `if` statement they follow, see [`generate_statements`].
+pub fn as_clause(atom: &Atom) -> Option<(&str, &[Atom])> {
+    if let AtomValue::STATEMENT(ref statement) = atom.value {
+        if let Some(Atom {
+            value: AtomValue::IDENTIFIER(ref identifier),
+            ..
+        }) = statement.first()
+        {
+            if identifier == ELIF_STR || identifier == ELSE_STR {
+                return Some((identifier.as_str(), &statement[1..]));
+            }
+        }
+    }
+    None
+}
+
+/// Get the atoms of the statement, if the atom is an `if` statement.
+fn as_if_statement(atom: &mut Atom) -> Option<&mut Vec<Atom>> {
+    if let AtomValue::STATEMENT(ref mut statement) = atom.value {
+        if let Some(Atom {
+            value: AtomValue::IDENTIFIER(ref identifier),
+            ..
+        }) = statement.first()
+        {
+            if identifier == IF_STR {
+                return Some(statement);
+            }
+        }
+    }
+    None
+}
+
+/// Move `elif` and `else` statements into the `if` statement they follow at the same
+/// indentation, so `if` receives its clauses as the trailing atoms of its body.
+fn fold_clauses(atoms: &mut Vec<Atom>) -> Result<(), Backtrace> {
+    let mut folded: Vec<Atom> = Vec::with_capacity(atoms.len());
+    for mut atom in atoms.drain(..) {
+        if let AtomValue::STATEMENT(ref mut statement) = atom.value {
+            fold_clauses(statement)?;
+        }
+
+        if let Some((keyword, _)) = as_clause(&atom) {
+            match folded.last_mut().and_then(as_if_statement) {
+                Some(statement) => {
+                    statement.push(atom);
+                    continue;
+                }
+                None => {
+                    raise_error!(
+                        Some(atom.mark.clone()),
+                        "'{}' must follow an '{}' statement at the same indentation.",
+                        keyword,
+                        IF_STR
+                    );
+                }
+            }
+        }
+        folded.push(atom);
+    }
+    *atoms = folded;
+    Ok(())
+}
@@ -11,6 +11,7 @@ use std::vec::Vec;
 pub enum TokenValue {
     WORD(String),
     STRING(String),
+    INT(i64),
     FLOAT(f64),
 }
 
@@ -35,12 +36,41 @@ impl Token {
         }
     }
 
+    pub fn new_int(int: i64, mark_line: MarkLine, column: RangeInclusive<usize>) -> Self {
+        Token {
+            value: TokenValue::INT(int),
+            mark: Mark::new(mark_line, column),
+        }
+    }
+
     pub fn new_float(float: f64, mark_line: MarkLine, column: RangeInclusive<usize>) -> Self {
         Token {
             value: TokenValue::FLOAT(float),
             mark: Mark::new(mark_line, column),
         }
     }
+
+    /// Make a number token out of the slice if it is a number, or a word token otherwise.
+    pub fn new_slice(
+        slice: &str,
+        mark_line: MarkLine,
+        column: RangeInclusive<usize>,
+    ) -> Result<Self, Backtrace> {
+        if let Ok(int) = slice.parse::<i64>() {
+            return Ok(Token::new_int(int, mark_line, column));
+        }
+        let digits = slice.strip_prefix(['-', '+']).unwrap_or(slice);
+        if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+            raise_error!(
+                Some(Mark::new(mark_line, column)),
+                "Integer literal is out of range."
+            );
+        }
+        if let Ok(float) = slice.parse::<f64>() {
+            return Ok(Token::new_float(float, mark_line, column));
+        }
+        Ok(Token::new_word(String::from(slice), mark_line, column))
+    }
 }
 
 #[derive(Debug)]
@@ -72,7 +102,10 @@ pub fn tokenize(name: String, code: String) -> Result<Vec<TokenLine>, Backtrace>
         let mut string_char = '\0';
         let mut slice_start = 0usize;
 
-        for (j, current_char) in line.chars().into_iter().enumerate() {
+        // Columns count characters, so non-ASCII text must not be sliced by bytes.
+        let chars: Vec<char> = line.chars().collect();
+
+        for (j, current_char) in chars.iter().copied().enumerate() {
             // Collect indentation count.
             if !is_indent_scanned {
                 if current_char.is_whitespace() {
@@ -110,7 +143,7 @@ pub fn tokenize(name: String, code: String) -> Result<Vec<TokenLine>, Backtrace>
             if string_char != '\0' {
                 if current_char == string_char {
                     token_line.tokens.push(Token::new_string(
-                        String::from(&line[slice_start..j]),
+                        chars[slice_start..j].iter().collect(),
                         mark_line.clone(),
                         slice_start..=j,
                     ));
@@ -132,21 +165,12 @@ pub fn tokenize(name: String, code: String) -> Result<Vec<TokenLine>, Backtrace>
 
                 // Check if there is unhandled token.
                 if slice_start != j {
-                    let slice = &line[slice_start..j];
-                    let parse_result = slice.parse::<f64>();
-                    if parse_result.is_ok() {
-                        token_line.tokens.push(Token::new_float(
-                            parse_result.unwrap(),
-                            mark_line.clone(),
-                            slice_start..=j - 1,
-                        ));
-                    } else {
-                        token_line.tokens.push(Token::new_word(
-                            String::from(slice),
-                            mark_line.clone(),
-                            slice_start..=j - 1,
-                        ));
-                    }
+                    let slice: String = chars[slice_start..j].iter().collect();
+                    token_line.tokens.push(Token::new_slice(
+                        &slice,
+                        mark_line.clone(),
+                        slice_start..=j - 1,
+                    )?);
                 }
 
                 // Push `TokenLine`.
@@ -158,21 +182,12 @@ pub fn tokenize(name: String, code: String) -> Result<Vec<TokenLine>, Backtrace>
             if current_char == '\'' {
                 string_char = current_char;
                 if slice_start != j {
-                    let slice = &line[slice_start..j];
-                    let parse_result = slice.parse::<f64>();
-                    if parse_result.is_ok() {
-                        token_line.tokens.push(Token::new_float(
-                            parse_result.unwrap(),
-                            mark_line.clone(),
-                            slice_start..=j,
-                        ));
-                    } else {
-                        token_line.tokens.push(Token::new_word(
-                            String::from(slice),
-                            mark_line.clone(),
-                            slice_start..=j,
-                        ));
-                    }
+                    let slice: String = chars[slice_start..j].iter().collect();
+                    token_line.tokens.push(Token::new_slice(
+                        &slice,
+                        mark_line.clone(),
+                        slice_start..=j,
+                    )?);
                 }
                 slice_start = j + 1;
                 continue;
@@ -181,28 +196,19 @@ pub fn tokenize(name: String, code: String) -> Result<Vec<TokenLine>, Backtrace>
             // Check if it is a whitespace.
             if current_char.is_whitespace() {
                 if slice_start != j {
-                    let slice = &line[slice_start..j];
-                    let parse_result = slice.parse::<f64>();
-                    if parse_result.is_ok() {
-                        token_line.tokens.push(Token::new_float(
-                            parse_result.unwrap(),
-                            mark_line.clone(),
-                            slice_start..=j,
-                        ));
-                    } else {
-                        token_line.tokens.push(Token::new_word(
-                            String::from(slice),
-                            mark_line.clone(),
-                            slice_start..=j,
-                        ));
-                    }
+                    let slice: String = chars[slice_start..j].iter().collect();
+                    token_line.tokens.push(Token::new_slice(
+                        &slice,
+                        mark_line.clone(),
+                        slice_start..=j,
+                    )?);
                 }
                 slice_start = j + 1;
                 continue;
             }
         }
 
-        let line_length = line.len();
+        let line_length = chars.len();
 
         // Check if unterminated string literal.
         if string_char != '\0' {
@@ -214,21 +220,12 @@ pub fn tokenize(name: String, code: String) -> Result<Vec<TokenLine>, Backtrace>
 
         // Check if there is unhandled token.
         if slice_start != line_length {
-            let slice = &line[slice_start..line_length];
-            let parse_result = slice.parse::<f64>();
-            if parse_result.is_ok() {
-                token_line.tokens.push(Token::new_float(
-                    parse_result.unwrap(),
-                    mark_line.clone(),
-                    slice_start..=line_length,
-                ));
-            } else {
-                token_line.tokens.push(Token::new_word(
-                    String::from(slice),
-                    mark_line.clone(),
-                    slice_start..=line_length,
-                ));
-            }
+            let slice: String = chars[slice_start..line_length].iter().collect();
+            token_line.tokens.push(Token::new_slice(
+                &slice,
+                mark_line.clone(),
+                slice_start..=line_length,
+            )?);
         }
 
         // Push `TokenLine`.
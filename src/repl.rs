@@ -0,0 +1,138 @@
+use crate::backtrace::Backtrace;
+use crate::interpreter::context::Context;
+use crate::interpreter::signal::Signal;
+use crate::interpreter::variant::represent::Represent;
+use crate::interpreter::variant::Variant;
+use crate::parser::atom::{generate_statements, AtomValue};
+use crate::parser::token::tokenize;
+use std::io::{self, BufRead, Write};
+
+const REPL_NAME: &str = "<repl>";
+const PROMPT: &str = ">>> ";
+const CONTINUATION_PROMPT: &str = "... ";
+
+/// Commands that take an indented block of statements.
+const BLOCK_COMMANDS: [&str; 9] = [
+    "when",
+    "if",
+    "while",
+    "for",
+    "closure",
+    "table",
+    "table-extend",
+    "console",
+    "try",
+];
+/// Commands that take their value from an indented line when it is left out.
+const VALUE_COMMANDS: [&str; 2] = ["var", "set"];
+
+/// Clauses written as unindented lines after the block of the statement they belong to.
+const CLAUSE_COMMANDS: [&str; 2] = ["elif", "else"];
+
+/// Whether the unindented line starts a statement that continues on indented lines.
+///
+/// Besides the commands taking a block, a table or a closure declared in the context runs the
+/// indented statements, or takes them as arguments, when it is the head of the statement.
+fn opens_block(context: &Context, line: &str) -> bool {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.first() {
+        Some(head) if BLOCK_COMMANDS.contains(head) => true,
+        Some(head) if VALUE_COMMANDS.contains(head) => words.len() == 2,
+        Some(head) => matches!(
+            context.lookup(&String::from(*head)),
+            Ok(Some(Variant::TABLE(_) | Variant::CLOSURE(_)))
+        ),
+        None => false,
+    }
+}
+
+/// Whether the unindented line adds a clause to the block before it.
+fn continues_block(line: &str) -> bool {
+    line.split_whitespace()
+        .next()
+        .is_some_and(|head| CLAUSE_COMMANDS.contains(&head))
+}
+
+/// Read-eval-print loop that keeps a single context alive across inputs.
+///
+/// An unindented line is evaluated as soon as it is entered. A line that opens an indented
+/// block, including one headed by a table or a closure, is collected with the indented lines
+/// and `elif` or `else` clauses after it, until a blank or other unindented line.
+pub fn run_repl(context: &mut Context) {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut block = String::new();
+
+    loop {
+        let prompt = if block.is_empty() {
+            PROMPT
+        } else {
+            CONTINUATION_PROMPT
+        };
+        print!("{prompt}");
+        let _ = io::stdout().flush();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(_)) | None => {
+                // End of input, evaluate whatever is left.
+                if !block.is_empty() {
+                    println!();
+                    evaluate(context, block.clone());
+                }
+                println!();
+                return;
+            }
+        };
+
+        if !block.is_empty() {
+            let is_indented = line.starts_with(char::is_whitespace) && !line.trim().is_empty();
+            if is_indented || continues_block(&line) {
+                block.push_str(&line);
+                block.push('\n');
+                continue;
+            }
+            // A blank or unindented line closes the block.
+            evaluate(context, block.clone());
+            block.clear();
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+        if opens_block(context, &line) {
+            block.push_str(&line);
+            block.push('\n');
+        } else {
+            evaluate(context, line);
+        }
+    }
+}
+
+fn evaluate(context: &mut Context, code: String) {
+    if let Err(error) = evaluate_statements(context, code) {
+        eprintln!("\n{:-^1$}", "Error", 60);
+        eprintln!("{}", error);
+    }
+}
+
+fn evaluate_statements(context: &mut Context, code: String) -> Result<(), Backtrace> {
+    let token_lines = tokenize(String::from(REPL_NAME), code)?;
+    let statements = generate_statements(token_lines)?;
+    for atom in statements.iter() {
+        let statement = match atom.value {
+            AtomValue::STATEMENT(ref statement) => statement,
+            _ => continue,
+        };
+        // `run_statement` keeps the global scope on the context, so declarations persist.
+        let signal = context.run_statement(statement.as_slice())?;
+        match signal {
+            Signal::COMPLETE(Variant::NULL(_)) => {}
+            Signal::COMPLETE(value) | Signal::RETURN(value, _) => {
+                println!("{}", value.represent_in(Some(atom.mark.clone()), context)?);
+            }
+            Signal::BREAK(_) | Signal::CONTINUE(_) => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::{eval, eval_error};
+
+#[test]
+fn integers_stay_integers() {
+    assert_eq!(eval("return\n    add 1 2 3\n"), "6");
+    assert_eq!(eval("return\n    sub 10 3 2\n"), "5");
+    assert_eq!(eval("return\n    mul 2 3 4\n"), "24");
+}
+
+#[test]
+fn mixing_with_floats_gives_floats() {
+    assert_eq!(eval("return\n    add 1 0.5\n"), "1.5");
+    assert_eq!(eval("return\n    mul 0.5 4\n"), "2.0");
+}
+
+#[test]
+fn division_is_exact_or_promoted() {
+    assert_eq!(eval("return\n    div 12 3\n"), "4");
+    assert_eq!(eval("return\n    div 7 2\n"), "3.5");
+}
+
+#[test]
+fn division_by_zero_is_an_error() {
+    assert!(eval_error("div 1 0\n").message().contains("by zero"));
+    assert!(eval_error("div 1 0.0\n").message().contains("by zero"));
+}
+
+#[test]
+fn overflow_is_an_error() {
+    let max = i64::MAX;
+    assert!(eval_error(&format!("add {} 1\n", max))
+        .message()
+        .contains("overflows"));
+    assert!(eval_error(&format!("mul {} 2\n", max))
+        .message()
+        .contains("overflows"));
+    assert!(eval_error(&format!("sub {} 1\n", i64::MIN))
+        .message()
+        .contains("overflows"));
+}
+
+#[test]
+fn math_commands_evaluate_each_argument_once() {
+    let code = "
+var numbers
+    list 2.0 2.0 3.0 -2.0
+var results
+    list
+list-push results
+    abs
+        list-pop numbers
+list-push results
+    pow
+        list-pop numbers
+        list-pop numbers
+list-push results
+    mod 7.5
+        list-pop numbers
+return results
+";
+    assert_eq!(eval(code), eval("return\n    list 2.0 9.0 1.5\n"));
+}
+
+#[test]
+fn mod_takes_the_sign_of_the_divisor() {
+    assert_eq!(eval("return\n    mod -1 3\n"), "2");
+    assert_eq!(eval("return\n    mod 1 -3\n"), "-2");
+    assert!(eval_error("mod 1 0\n").message().contains("by zero"));
+}
+
+#[test]
+fn pow_keeps_integers_for_non_negative_exponents() {
+    assert_eq!(eval("return\n    pow 2 10\n"), "1024");
+    assert_eq!(eval("return\n    pow 2 -1\n"), "0.5");
+    assert!(eval_error("pow 2 64\n").message().contains("overflows"));
+}
+
+#[test]
+fn abs_rejects_non_numbers() {
+    assert!(eval_error("abs 'a'\n").message().contains("not a number"));
+}
@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+
+use blinklet::backtrace::Backtrace;
+use blinklet::interpreter::context::Context;
+use blinklet::interpreter::resource::ResourcePath;
+use blinklet::interpreter::signal::Signal;
+use blinklet::interpreter::variant::represent::Represent;
+use blinklet::interpreter::variant::Variant;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Run the code in the context and return the value it returns.
+pub fn run_in(context: &mut Context, code: &str) -> Result<Variant, Backtrace> {
+    match context.run_code(String::from("test"), String::from(code))? {
+        Signal::COMPLETE(value) | Signal::RETURN(value, _) => Ok(value),
+        _ => panic!("Unexpected control signal."),
+    }
+}
+
+/// Run the code in a new context and return the representation of the value it returns.
+pub fn eval(code: &str) -> String {
+    let mut context = Context::new().unwrap();
+    let value = run_in(&mut context, code).unwrap_or_else(|error| panic!("{}", error));
+    value.represent_in(None, &mut context).unwrap()
+}
+
+/// Run the code in a new context, expecting it to fail.
+pub fn eval_error(code: &str) -> Backtrace {
+    let mut context = Context::new().unwrap();
+    match run_in(&mut context, code) {
+        Ok(value) => panic!(
+            "Expecting an error, got {}.",
+            value.represent(None).unwrap()
+        ),
+        Err(error) => error,
+    }
+}
+
+/// Row and first column of the mark the error is raised at, both 0-based.
+pub fn error_position(error: &Backtrace) -> (usize, usize) {
+    let mark = error.mark().expect("The error is not marked.");
+    (mark.line.row, *mark.column.start())
+}
+
+/// A new directory in the temporary directory of the system, removed with everything in it when
+/// dropped, even if the test fails.
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Make the directory, `name` keeps it apart from the directories of other tests.
+    pub fn new(name: &str) -> TempDir {
+        let path = std::env::temp_dir().join(format!("blinklet-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir {
+            path: fs::canonicalize(path).unwrap(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write each `(path, code)` into the directory, making the directories on the way.
+    pub fn write(&self, scripts: &[(&str, &str)]) {
+        for (path, code) in scripts {
+            let path = self.path.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, code).unwrap();
+        }
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Run the script at `path` in the context and return the representation of its value.
+pub fn run_resource_in(context: &mut Context, path: ResourcePath) -> Result<String, Backtrace> {
+    match context.run_resource(path)? {
+        Signal::COMPLETE(value) => Ok(value.represent_in(None, context).unwrap()),
+        _ => panic!("Unexpected control signal."),
+    }
+}
+
+/// Write the scripts into a new temporary directory and run `main.k` in it.
+pub fn run_scripts(name: &str, scripts: &[(&str, &str)]) -> Result<String, Backtrace> {
+    let directory = TempDir::new(name);
+    directory.write(scripts);
+    let path = ResourcePath::try_from(directory.path().join("main.k")).unwrap();
+    run_resource_in(&mut Context::new().unwrap(), path)
+}
@@ -0,0 +1,259 @@
+mod common;
+
+use common::{error_position, eval, eval_error};
+
+#[test]
+fn if_returns_the_last_statement_of_the_chosen_clause() {
+    let code = "
+var x 5
+return
+    if
+        l x 3
+        add 1 1
+    elif
+        l x 10
+        add 1 1
+        add 10 10
+    else
+        add 0 0
+";
+    assert_eq!(eval(code), "20");
+}
+
+#[test]
+fn if_runs_else_when_no_condition_holds() {
+    assert_eq!(
+        eval("return\n    if false\n        add 1 1\n    else\n        add 2 2\n"),
+        "4"
+    );
+}
+
+#[test]
+fn if_returns_null_when_no_clause_runs() {
+    assert_eq!(eval("return\n    if false\n        add 1 1\n"), "null");
+}
+
+#[test]
+fn else_belongs_to_the_if_at_its_indentation() {
+    let code = "
+var result 'none'
+if true
+    if false
+        set result 'inner if'
+    else
+        set result 'inner else'
+else
+    set result 'outer else'
+return result
+";
+    assert_eq!(eval(code), "inner else");
+}
+
+#[test]
+fn else_nested_inside_if_is_rejected() {
+    let error = eval_error("if true\n    add 1 1\n    else\n        add 2 2\n");
+    assert!(error.message().contains("must follow an 'if'"));
+    assert_eq!(error_position(&error).0, 2);
+}
+
+#[test]
+fn else_without_if_is_rejected() {
+    let error = eval_error("add 1 1\nelse\n    add 2 2\n");
+    assert!(error.message().contains("must follow an 'if'"));
+}
+
+#[test]
+fn else_must_be_the_last_clause() {
+    let error = eval_error("if false\n    add 1 1\nelse\n    add 2 2\nelif true\n    add 3 3\n");
+    assert!(error.message().contains("must be the last clause"));
+}
+
+#[test]
+fn for_takes_the_iterable_from_the_line() {
+    let code = "
+var total 0
+var numbers
+    list 1 2 3
+for number numbers
+    set total
+        add total number
+return total
+";
+    assert_eq!(eval(code), "6");
+}
+
+#[test]
+fn for_takes_the_iterable_from_the_first_statement() {
+    let code = "
+var total 0
+for number
+    list 1 2 3
+    set total
+        add total number
+return total
+";
+    assert_eq!(eval(code), "6");
+}
+
+#[test]
+fn for_binds_key_and_value_of_a_table() {
+    let code = "
+var keys ''
+var total 0
+var entries
+    table
+        var a 1
+        var b 2
+for key value entries
+    set keys
+        add keys key
+    set total
+        add total value
+return
+    list keys total
+";
+    assert_eq!(eval(code), eval("return\n    list 'ab' 3\n"));
+}
+
+#[test]
+fn for_rejects_extra_atoms_on_its_line() {
+    let error = eval_error("for a b c d\n    add 1 1\n");
+    assert!(error.message().contains("at most two identifiers"));
+    assert_eq!(error_position(&error), (0, 10));
+}
+
+#[test]
+fn for_rejects_a_literal_value_identifier() {
+    let error = eval_error("for a 1 c\n    add 1 1\n");
+    assert!(error.message().contains("Expecting an identifier"));
+}
+
+#[test]
+fn try_returns_the_last_statement_of_its_commands() {
+    let code = "
+return
+    try
+        add 1 1
+        add 1 2
+        catch error
+            add 0 0
+";
+    assert_eq!(eval(code), "3");
+}
+
+#[test]
+fn try_returns_the_last_statement_of_catch_on_error() {
+    let code = "
+return
+    try
+        throw 'oops'
+        add 1 1
+        catch error
+            table-get error 'message'
+";
+    assert_eq!(eval(code), "oops");
+}
+
+#[test]
+fn catch_describes_the_error() {
+    let code = "
+var described
+    try
+        add 1 1
+        throw 42
+        catch error
+            list
+                table-get error 'message'
+                table-get error 'value'
+                table-get error 'name'
+                table-get error 'row'
+                table-get error 'column'
+return described
+";
+    assert_eq!(eval(code), "[\"42\", 42, \"test\", 5, 9]");
+
+    let code = "
+var error
+    try
+        throw 42
+        catch error
+            error
+return
+    table-get error 'backtrace'
+";
+    assert!(eval(code).contains("Error: 42"));
+}
+
+#[test]
+fn catch_recovers_from_errors_of_commands() {
+    let code = "
+var numbers
+    list 1
+return
+    try
+        list-get numbers 5
+        catch error
+            table-get error 'value'
+";
+    assert_eq!(eval(code), "null");
+}
+
+#[test]
+fn catch_binding_does_not_leak() {
+    let error = eval_error("try\n    throw 1\n    catch error\n        add 1 1\nreturn error\n");
+    assert!(error.message().contains("'error' is not defined"));
+}
+
+#[test]
+fn uncaught_throw_is_an_error_at_throw() {
+    let error = eval_error("add 1 1\nthrow 'oops'\n");
+    assert_eq!(error.message(), "oops");
+    assert_eq!(error_position(&error), (1, 0));
+}
+
+#[test]
+fn try_restores_scopes_and_arguments_after_an_error() {
+    let code = "
+var thrower
+    closure
+        parameter value
+        var local 'thrower'
+        throw value
+var f
+    closure
+        var caught
+            try
+                thrower 'boom'
+                catch error
+                    table-get error 'value'
+        parameter a b
+        var local 'f'
+        return
+            list a b caught local
+return
+    f 1 2
+";
+    assert_eq!(eval(code), "[1, 2, \"boom\", \"f\"]");
+}
+
+#[test]
+fn variables_stay_reachable_after_errors_in_loops() {
+    let code = "
+var count 0
+var caught 0
+while running
+    l count 3
+    set count
+        add count 1
+    try
+        var inner count
+        throw inner
+        catch error
+            set caught
+                add caught
+                    table-get error 'value'
+return
+    list count caught
+";
+    assert_eq!(eval(code), "[3, 6]");
+}
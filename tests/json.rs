@@ -0,0 +1,46 @@
+mod common;
+
+use common::{error_position, eval, eval_error};
+
+fn parse(text: &str) -> String {
+    eval(&format!("return\n    json-parse '{}'\n", text))
+}
+
+fn parse_error(text: &str) -> String {
+    eval_error(&format!("json-parse '{}'\n", text))
+        .message()
+        .to_string()
+}
+
+#[test]
+fn parses_whole_numbers_as_integers() {
+    assert_eq!(
+        parse("[0, -0, 12, -340]"),
+        eval("return\n    list 0 0 12 -340\n")
+    );
+}
+
+#[test]
+fn parses_fractions_and_exponents_as_floats() {
+    assert_eq!(parse("1.5e-3"), eval("return 0.0015\n"));
+    assert_eq!(parse("2E2"), eval("return 200.0\n"));
+    assert_eq!(parse("-0.25"), eval("return -0.25\n"));
+}
+
+#[test]
+fn rejects_numbers_outside_the_grammar() {
+    assert!(parse_error("01").contains("Leading zeros"));
+    assert!(parse_error("-").contains("Expecting a digit."));
+    assert!(parse_error("1.").contains("after '.'"));
+    assert!(parse_error("1e").contains("exponent"));
+    assert!(parse_error("1e+").contains("exponent"));
+    assert!(!parse_error("1-2").is_empty());
+    assert!(!parse_error("+1").is_empty());
+    assert!(!parse_error(".5").is_empty());
+}
+
+#[test]
+fn marks_input_that_is_not_a_string() {
+    let error = eval_error("json-parse 12\n");
+    assert_eq!(error_position(&error), (0, 11));
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use common::{error_position, eval, eval_error};
+
+const NUMBERS: &str = "
+var numbers
+    list 1 2 3 4
+var calls 0
+var is-even
+    closure
+        parameter number
+        set calls
+            add calls 1
+        return
+            eq 0
+                mod number 2
+";
+
+#[test]
+fn list_any_stops_at_the_first_match() {
+    let code =
+        format!("{NUMBERS}var found\n    list-any numbers is-even\nreturn\n    list found calls\n");
+    assert_eq!(eval(&code), eval("return\n    list true 2\n"));
+}
+
+#[test]
+fn list_all_stops_at_the_first_failure() {
+    let code =
+        format!("{NUMBERS}var found\n    list-all numbers is-even\nreturn\n    list found calls\n");
+    assert_eq!(eval(&code), eval("return\n    list false 1\n"));
+}
+
+#[test]
+fn list_any_and_all_of_an_empty_list() {
+    let code = "var empty\n    list\nvar never\n    closure\n        parameter x\n        return false\nreturn\n    list\n        list-any empty never\n        list-all empty never\n";
+    assert_eq!(eval(code), eval("return\n    list false true\n"));
+}
+
+#[test]
+fn list_any_requires_a_boolean_callback() {
+    let code = "var numbers\n    list 1\nvar same\n    closure\n        parameter x\n        return x\nlist-any numbers same\n";
+    assert!(eval_error(code).message().contains("must return a boolean"));
+}
+
+const DIGITS: &str = "
+var digits
+    list 1 2 3 4
+";
+
+#[test]
+fn negative_indices_count_from_the_end() {
+    let code = format!(
+        "{DIGITS}var last\n    list-get digits -1\nvar first\n    list-get digits -4\nlist-set digits -2 0\nvar removed\n    list-remove digits -1\nreturn\n    list last first removed digits\n"
+    );
+    assert_eq!(eval(&code), "[4, 1, 4, [1, 2, 0]]");
+}
+
+#[test]
+fn indices_out_of_bound_are_an_error() {
+    let error = eval_error(&format!("{DIGITS}list-get digits 4\n"));
+    assert_eq!(error.message(), "Index `4` is out of bound for length 4.");
+    assert_eq!(error_position(&error), (3, 0));
+
+    let error = eval_error(&format!("{DIGITS}list-get digits -5\n"));
+    assert_eq!(error.message(), "Index `-5` is out of bound for length 4.");
+}
+
+#[test]
+fn list_insert_at_the_length_appends() {
+    let code = format!("{DIGITS}list-insert digits 4 5\nlist-insert digits 0 0\nreturn digits\n");
+    assert_eq!(eval(&code), "[0, 1, 2, 3, 4, 5]");
+
+    let error = eval_error(&format!("{DIGITS}list-insert digits 5 0\n"));
+    assert_eq!(error.message(), "Index `5` is out of bound for length 4.");
+}
+
+#[test]
+fn list_slice_bounds() {
+    let code = format!(
+        "{DIGITS}return\n    list\n        list-slice digits 1 3\n        list-slice digits -2\n        list-slice digits 2 2\n        list-slice digits 4\n"
+    );
+    assert_eq!(eval(&code), "[[2, 3], [3, 4], [], []]");
+
+    let error = eval_error(&format!("{DIGITS}list-slice digits 3 2\n"));
+    assert_eq!(error.message(), "Slice starts after it ends.");
+
+    let error = eval_error(&format!("{DIGITS}list-slice digits 0 5\n"));
+    assert_eq!(error.message(), "Index `5` is out of bound for length 4.");
+}
+
+#[test]
+fn add_concatenates_lists() {
+    let code = format!("{DIGITS}return\n    add digits\n        list 5\n        list\n");
+    assert_eq!(eval(&code), "[1, 2, 3, 4, 5]");
+    assert_eq!(
+        eval(&format!("{DIGITS}add digits\n    list 5\nreturn digits\n")),
+        "[1, 2, 3, 4]"
+    );
+
+    let error = eval_error(&format!("{DIGITS}add digits 1\n"));
+    assert_eq!(error.message(), "`[1, 2, 3, 4]` cannot be added with `1`.");
+}
+
+#[test]
+fn list_sort_orders_numbers_and_strings() {
+    let code = "return\n    list\n        list-sort\n            list 3 1.5 2\n        list-sort\n            list 'b' 'a'\n";
+    assert_eq!(eval(code), "[[1.5, 2, 3], [\"a\", \"b\"]]");
+}
+
+#[test]
+fn list_sort_needs_a_comparator_for_operands_it_cannot_order() {
+    let error = eval_error("add 1 1\nlist-sort\n    list 3 'a' 2\n");
+    assert_eq!(
+        error.message(),
+        "`2` and `a` cannot be ordered without a comparator."
+    );
+    assert_eq!(error_position(&error), (1, 0));
+
+    let code = "
+var by-length
+    closure
+        parameter lhs rhs
+        return
+            l
+                string-length lhs
+                string-length rhs
+return
+    list-sort
+        list 'ccc' 'a' 'bb'
+        | by-length
+";
+    assert_eq!(eval(code), "[\"a\", \"bb\", \"ccc\"]");
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::run_scripts;
+
+#[test]
+fn modules_run_once_and_share_their_value() {
+    let result = run_scripts(
+        "cache",
+        &[
+            ("counter.k", "var count 0\n"),
+            (
+                "main.k",
+                "import counter as a\nimport counter as b\nb\n    set count 1\nreturn\n    a\n        return count\n",
+            ),
+        ],
+    );
+    assert_eq!(result.unwrap(), "1");
+}
+
+#[test]
+fn export_limits_the_module_value() {
+    let result = run_scripts(
+        "export",
+        &[
+            ("shapes.k", "var area 1\nvar secret 2\nexport area\n"),
+            ("main.k", "import shapes as shapes\nreturn shapes\n"),
+        ],
+    );
+    assert_eq!(result.unwrap(), "<Table {area: 1}>");
+}
+
+#[test]
+fn circular_imports_are_an_error() {
+    let result = run_scripts(
+        "circular",
+        &[
+            ("a.k", "import b\n"),
+            ("b.k", "import a\n"),
+            ("main.k", "import a\n"),
+        ],
+    );
+    assert!(result.unwrap_err().message().contains("ircular"));
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use blinklet::interpreter::context::Context;
+use common::{error_position, run_in};
+
+#[test]
+fn converts_arguments() {
+    let mut context = Context::new().unwrap();
+    context.register_fn("hypot", |a: f64, b: f64| Ok::<_, String>(a.hypot(b)));
+    let value = run_in(&mut context, "return\n    hypot 3 4\n").unwrap();
+    assert_eq!(f64::try_from(value).unwrap(), 5.0);
+}
+
+#[test]
+fn marks_the_argument_that_fails_to_convert() {
+    let mut context = Context::new().unwrap();
+    context.register_fn("hypot", |a: f64, b: f64| Ok::<_, String>(a.hypot(b)));
+    let error = run_in(&mut context, "hypot 3 'four'\n").unwrap_err();
+    // Strings are marked from their first character, inside the quote.
+    assert_eq!(error_position(&error), (0, 9));
+}
+
+#[test]
+fn checks_the_argument_count() {
+    let mut context = Context::new().unwrap();
+    context.register_fn("hypot", |a: f64, b: f64| Ok::<_, String>(a.hypot(b)));
+    let error = run_in(&mut context, "hypot 3\n").unwrap_err();
+    assert!(error.message().contains("Expecting 2 argument(s)"));
+}
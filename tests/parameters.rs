@@ -0,0 +1,96 @@
+mod common;
+
+use common::{error_position, eval, eval_error};
+
+const DEFAULTS: &str = "
+var f
+    closure
+        parameter a
+            b
+                add a 1
+            | ...rest
+        return
+            list a b rest
+";
+
+#[test]
+fn defaults_refer_to_earlier_parameters() {
+    let code = format!("{}return\n    list\n        f 1\n        f 1 5\n", DEFAULTS);
+    assert_eq!(eval(&code), "[[1, 2, []], [1, 5, []]]");
+}
+
+#[test]
+fn rest_collects_the_remaining_arguments() {
+    let code = format!("{}return\n    f 1 5 6 7\n", DEFAULTS);
+    assert_eq!(eval(&code), "[1, 5, [6, 7]]");
+}
+
+#[test]
+fn keywords_bind_in_any_order() {
+    let code = format!("{}return\n    f b: 3 a: 2\n", DEFAULTS);
+    assert_eq!(eval(&code), "[2, 3, []]");
+}
+
+#[test]
+fn keyword_and_positional_arguments_conflict() {
+    let code = "
+var pair
+    closure
+        parameter a b
+        return a
+pair 1 a: 2
+";
+    let error = eval_error(code);
+    assert_eq!(
+        error.message(),
+        "Argument 'a' is given both by position and by keyword."
+    );
+    assert_eq!(error_position(&error), (5, 0));
+}
+
+#[test]
+fn arity_errors_are_marked_at_the_call_with_a_note_on_the_parameters() {
+    let code = "
+var pair
+    closure
+        parameter a b
+        return a
+
+pair 1
+";
+    let error = eval_error(code);
+    assert_eq!(error.message(), "Argument 'b' is not given.");
+    assert_eq!(error_position(&error), (6, 0));
+    let rendering = format!("{}", error);
+    let note = rendering
+        .find("Note: The parameters are declared here.")
+        .expect("The error has no note.");
+    assert!(rendering[note..].contains("        parameter a b"));
+
+    let error = eval_error("var pair\n    closure\n        parameter a b\npair 1 2 3\n");
+    assert_eq!(error.message(), "Expecting 2 argument(s), but 3 are given.");
+    assert_eq!(error_position(&error), (3, 0));
+}
+
+#[test]
+fn closures_without_parameter_take_no_arguments() {
+    let code = "
+var answer
+    closure
+        return 42
+answer 1 2
+";
+    let error = eval_error(code);
+    assert_eq!(error.message(), "Expecting 0 argument(s), but 2 are given.");
+    assert_eq!(error_position(&error), (4, 0));
+    assert!(format!("{}", error).contains("Note: The closure is declared here."));
+
+    let code = "
+var answer
+    closure
+        return 42
+answer key: 1
+";
+    let error = eval_error(code);
+    assert_eq!(error.message(), "Unknown keyword argument 'key'.");
+}
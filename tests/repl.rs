@@ -0,0 +1,49 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Feed the input to an interactive session and return what it prints.
+fn session(input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_blinklet"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn evaluates_unindented_lines_immediately() {
+    assert_eq!(session("println 1\nprintln 2\n"), ">>> 1\n>>> 2\n>>> \n");
+}
+
+#[test]
+fn collects_indented_blocks() {
+    assert_eq!(
+        session("var x\n    add 1 2\n\nprintln x\n"),
+        ">>> ... ... >>> 3\n>>> \n"
+    );
+}
+
+#[test]
+fn continues_blocks_on_elif_and_else() {
+    assert_eq!(
+        session("if false\n    println 1\nelse\n    println 2\nprintln 3\n"),
+        ">>> ... ... ... ... 2\n3\n>>> \n"
+    );
+}
+
+#[test]
+fn collects_blocks_headed_by_tables() {
+    assert_eq!(
+        session("var p\n    table\n        var age 3\n\np\n    return age\nprintln 4\n"),
+        ">>> ... ... ... >>> ... ... 3\n4\n>>> \n"
+    );
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{eval, eval_error};
+
+const POINT: &str = "
+var point
+    table
+        var x 1
+        var __repr
+            closure
+                parameter self
+                return 'point'
+";
+
+#[test]
+fn tables_are_represented_with_repr() {
+    assert_eq!(eval(&format!("{POINT}return '`point`'\n")), "point");
+}
+
+#[test]
+fn repr_representing_its_own_table_shows_the_entries() {
+    let code = "
+var point
+    table
+        var x 1
+        var __repr
+            closure
+                parameter self
+                return 'point `self`'
+return '`point`'
+";
+    let representation = eval(code);
+    assert!(representation.starts_with("point "));
+    assert!(representation.contains("x"));
+}
+
+#[test]
+fn assert_message_uses_repr() {
+    let error = eval_error(&format!("{POINT}assert false point\n"));
+    assert_eq!(error.message(), "point");
+}
+
+#[test]
+fn for_reports_what_is_not_iterable() {
+    let error = eval_error("for x 1\n    add 1 1\n");
+    assert!(error.message().contains("`1` is not iterable."));
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use blinklet::interpreter::context::Context;
+use blinklet::interpreter::resource::chain_resource::ChainResource;
+use blinklet::interpreter::resource::embedded_resource::bundle_directory;
+use blinklet::interpreter::resource::in_memory_resource::InMemoryResource;
+use blinklet::interpreter::resource::{Resource, ResourcePath};
+use common::{run_resource_in, TempDir};
+use std::fs;
+
+/// Run `main.k` of the resource and return the representation of its value.
+fn run_main(resource: Box<dyn Resource>) -> String {
+    let mut context = Context::new().unwrap();
+    context.resource = resource;
+    let path = ResourcePath::try_from(String::from("main.k")).unwrap();
+    run_resource_in(&mut context, path).unwrap_or_else(|error| panic!("{}", error))
+}
+
+fn in_memory(scripts: &[(&str, &str)]) -> Box<dyn Resource> {
+    Box::new(InMemoryResource::try_from(scripts).unwrap())
+}
+
+/// Take the path and file of each `(path, include_str!(file))` entry written by
+/// [`bundle_directory`].
+fn read_bundle(source: &str) -> Vec<(String, String)> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("(\""))
+        .map(|line| {
+            let (path, rest) = line.split_once("\", include_str!(\"").unwrap();
+            let (file, _) = rest.split_once("\")").unwrap();
+            (path.to_string(), file.replace("\\\\", "\\"))
+        })
+        .collect()
+}
+
+#[test]
+fn bundled_scripts_are_served_in_memory() {
+    let directory = TempDir::new("bundle");
+    directory.write(&[
+        (
+            "scripts/main.k",
+            "import lib::math as math\nreturn\n    math\n        return answer\n",
+        ),
+        ("scripts/lib/math.k", "var answer 42\n"),
+        ("scripts/notes.txt", "Not a script."),
+    ]);
+
+    let output = directory.path().join("scripts.rs");
+    bundle_directory(&directory.path().join("scripts"), &output).unwrap();
+    let bundle = read_bundle(&fs::read_to_string(&output).unwrap());
+    let scripts: Vec<(String, String)> = bundle
+        .into_iter()
+        .map(|(path, file)| (path, fs::read_to_string(file).unwrap()))
+        .collect();
+
+    let paths: Vec<&str> = scripts.iter().map(|(path, _)| path.as_str()).collect();
+    assert_eq!(paths, vec!["lib::math.k", "main.k"]);
+    let scripts: Vec<(&str, &str)> = scripts
+        .iter()
+        .map(|(path, code)| (path.as_str(), code.as_str()))
+        .collect();
+    assert_eq!(run_main(in_memory(&scripts)), "42");
+}
+
+#[test]
+fn chain_falls_back_to_the_next_resource() {
+    let resources: Vec<Box<dyn Resource>> = vec![
+        in_memory(&[(
+            "main.k",
+            "import util as util\nreturn\n    util\n        return name\n",
+        )]),
+        in_memory(&[
+            ("util.k", "var name 'second'\n"),
+            ("main.k", "return 'shadowed'\n"),
+        ]),
+    ];
+    assert_eq!(run_main(Box::new(ChainResource::from(resources))), "second");
+}
+
+#[test]
+fn chain_reports_every_resource_tried() {
+    let resources: Vec<Box<dyn Resource>> =
+        vec![in_memory(&[("main.k", "import missing\n")]), in_memory(&[])];
+    let mut context = Context::new().unwrap();
+    context.resource = Box::new(ChainResource::from(resources));
+    let path = ResourcePath::try_from(String::from("main.k")).unwrap();
+    let error = context.run_resource(path).unwrap_err();
+    assert_eq!(
+        error
+            .message()
+            .matches("Unable to find script 'missing.k'")
+            .count(),
+        2
+    );
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use blinklet::backtrace::Backtrace;
+use blinklet::interpreter::capability::Capabilities;
+use blinklet::interpreter::context::Context;
+use blinklet::interpreter::resource::system_resource::SystemResource;
+use blinklet::interpreter::resource::ResourcePath;
+use common::{run_resource_in, TempDir};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A directory with an `app` directory allowed for imports and an `outside` directory that is not.
+struct Sandbox {
+    directory: TempDir,
+}
+
+impl Sandbox {
+    fn new(name: &str) -> Sandbox {
+        let directory = TempDir::new(name);
+        directory.write(&[
+            ("app/inside.k", "var x 'inside'\n"),
+            ("outside/secret.k", "var x 'secret'\n"),
+        ]);
+        Sandbox { directory }
+    }
+
+    fn app(&self) -> PathBuf {
+        self.directory.path().join("app")
+    }
+
+    /// Run `code` as `main.k` of `app` with only `app` allowed for imports, while `outside` is
+    /// searched for imports too.
+    fn run(&self, code: &str) -> Result<String, Backtrace> {
+        let main = self.app().join("main.k");
+        fs::write(&main, code).unwrap();
+        let mut resource = SystemResource::default();
+        resource
+            .add_root(self.directory.path().join("outside"))
+            .unwrap();
+        let mut context = Context::new().unwrap();
+        context.resource = Box::new(resource);
+        let mut capabilities = Capabilities::sandbox();
+        capabilities.import_roots = Some(vec![ResourcePath::try_from(self.app()).unwrap()]);
+        context.capabilities = capabilities;
+        run_resource_in(&mut context, ResourcePath::try_from(main).unwrap())
+    }
+}
+
+fn assert_denied(result: Result<String, Backtrace>, message: &str) {
+    match result {
+        Ok(_) => panic!("Expecting '{}'.", message),
+        Err(error) => assert!(error.message().contains(message), "{}", error),
+    }
+}
+
+#[test]
+fn imports_inside_the_roots_are_allowed() {
+    let sandbox = Sandbox::new("sandbox-inside");
+    assert!(sandbox.run("import inside\n").is_ok());
+}
+
+#[test]
+fn imports_outside_the_roots_are_denied() {
+    let sandbox = Sandbox::new("sandbox-outside");
+    assert_denied(sandbox.run("import secret\n"), "is not allowed");
+}
+
+#[cfg(unix)]
+#[test]
+fn symbolic_links_out_of_the_roots_are_denied() {
+    use std::os::unix::fs::symlink;
+
+    let sandbox = Sandbox::new("sandbox-symlink");
+    let outside = sandbox.directory.path().join("outside");
+    symlink(outside.join("secret.k"), sandbox.app().join("link.k")).unwrap();
+    symlink(&outside, sandbox.app().join("linked")).unwrap();
+    assert_denied(sandbox.run("import link\n"), "is not allowed");
+    assert_denied(sandbox.run("import linked::secret\n"), "is not allowed");
+}
+
+#[test]
+fn console_is_denied() {
+    let sandbox = Sandbox::new("sandbox-console");
+    assert_denied(
+        sandbox.run("console\n    echo 'hi'\n"),
+        "Running 'echo' with `console` is not allowed.",
+    );
+}
+
+#[test]
+fn files_and_network_are_denied() {
+    let capabilities = Capabilities::sandbox();
+    assert!(capabilities.check_file(None).is_err());
+    assert!(capabilities.check_network(None).is_err());
+    assert!(Capabilities::default().check_file(None).is_ok());
+    assert!(capabilities
+        .check_import(&ResourcePath::try_from(Path::new("/any")).unwrap(), None)
+        .is_err());
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use blinklet::interpreter::context::Context;
+use blinklet::interpreter::variant::represent::Represent;
+use blinklet::interpreter::variant::serialization::{from_variant, to_variant};
+use common::run_in;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Fast,
+    Retry(u32),
+    Range(i64, i64),
+    Window { width: u32, height: u32 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Server {
+    host: String,
+    port: u16,
+    tags: Vec<String>,
+    timeout: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    debug: bool,
+    servers: Vec<Server>,
+    limits: HashMap<String, i64>,
+    fallback: Option<Server>,
+    modes: Vec<Mode>,
+}
+
+fn config() -> Config {
+    Config {
+        name: String::from("app"),
+        debug: false,
+        servers: vec![
+            Server {
+                host: String::from("a"),
+                port: 80,
+                tags: vec![String::from("web"), String::from("primary")],
+                timeout: Some(2.5),
+            },
+            Server {
+                host: String::from("b"),
+                port: 8080,
+                tags: Vec::new(),
+                timeout: None,
+            },
+        ],
+        limits: HashMap::from([(String::from("cpu"), 4), (String::from("memory"), -1)]),
+        fallback: None,
+        modes: vec![
+            Mode::Fast,
+            Mode::Retry(3),
+            Mode::Range(-2, 2),
+            Mode::Window {
+                width: 640,
+                height: 480,
+            },
+        ],
+    }
+}
+
+#[test]
+fn nested_structs_round_trip_through_tables() {
+    let variant = to_variant(&config()).unwrap();
+    assert_eq!(from_variant::<Config>(variant).unwrap(), config());
+}
+
+#[test]
+fn structs_become_tables_in_field_order() {
+    let server = Server {
+        host: String::from("a"),
+        port: 80,
+        tags: vec![String::from("web")],
+        timeout: None,
+    };
+    assert_eq!(
+        to_variant(&server).unwrap().represent(None).unwrap(),
+        "<Table {host: \"a\", port: 80, tags: [\"web\"], timeout: null}>"
+    );
+}
+
+#[test]
+fn enums_become_strings_or_single_entry_tables() {
+    let modes = to_variant(&config().modes).unwrap();
+    assert_eq!(
+        modes.represent(None).unwrap(),
+        "[\"Fast\", <Table {Retry: 3}>, <Table {Range: [-2, 2]}>, \
+         <Table {Window: <Table {width: 640, height: 480}>}>]"
+    );
+}
+
+#[test]
+fn structs_are_read_from_script_tables() {
+    let code = "
+return
+    table
+        var host 'c'
+        var port 443
+        var tags
+            list 'secure'
+";
+    let mut context = Context::new().unwrap();
+    let variant = run_in(&mut context, code).unwrap();
+    let server = from_variant::<Server>(variant).unwrap();
+    assert_eq!(
+        server,
+        Server {
+            host: String::from("c"),
+            port: 443,
+            tags: vec![String::from("secure")],
+            timeout: None,
+        }
+    );
+}
+
+#[test]
+fn mismatched_tables_are_an_error() {
+    let code = "
+return
+    table
+        var host 'c'
+        var port 'https'
+        var tags
+            list
+";
+    let mut context = Context::new().unwrap();
+    let variant = run_in(&mut context, code).unwrap();
+    assert!(from_variant::<Server>(variant).is_err());
+}
+
+#[test]
+fn unsigned_integers_beyond_integers_are_an_error() {
+    let error = to_variant(&u64::MAX).unwrap_err();
+    assert!(error.message().contains("does not fit"));
+
+    let largest = i64::MAX as u64;
+    let variant = to_variant(&largest).unwrap();
+    assert_eq!(from_variant::<u64>(variant).unwrap(), largest);
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::{error_position, eval, eval_error};
+
+const WORDS: &str = "
+var words 'héllo wörld'
+";
+
+#[test]
+fn string_slice_counts_characters() {
+    let code = format!(
+        "{WORDS}return\n    list\n        string-length words\n        string-slice words 1 4\n        string-slice words 6\n        string-slice words 11\n"
+    );
+    assert_eq!(eval(&code), "[11, \"éll\", \"wörld\", \"\"]");
+}
+
+#[test]
+fn string_slice_counts_negative_indices_from_the_end() {
+    let code = format!(
+        "{WORDS}return\n    list\n        string-slice words -5\n        string-slice words -5 -1\n"
+    );
+    assert_eq!(eval(&code), "[\"wörld\", \"wörl\"]");
+}
+
+#[test]
+fn string_slice_bounds_are_checked() {
+    let error = eval_error(&format!("{WORDS}string-slice words 0 12\n"));
+    assert_eq!(error.message(), "Index `12` is out of bound for length 11.");
+    assert_eq!(error_position(&error), (2, 0));
+
+    let error = eval_error(&format!("{WORDS}string-slice words -12\n"));
+    assert_eq!(
+        error.message(),
+        "Index `-12` is out of bound for length 11."
+    );
+
+    let error = eval_error(&format!("{WORDS}string-slice words 3 2\n"));
+    assert_eq!(error.message(), "Slice starts after it ends.");
+}
+
+#[test]
+fn string_find_returns_character_indices() {
+    let code = format!(
+        "{WORDS}return\n    list\n        string-find words 'wö'\n        string-find words 'l'\n        string-find words 'z'\n"
+    );
+    assert_eq!(eval(&code), "[6, 2, null]");
+}
+
+#[test]
+fn string_chars_splits_multi_byte_characters() {
+    let code = "return\n    list\n        string-chars 'añb'\n        string-chars ''\n";
+    assert_eq!(eval(code), "[[\"a\", \"ñ\", \"b\"], []]");
+}
+
+#[test]
+fn strings_are_compared_in_lexicographic_order() {
+    let code = "return\n    list\n        g 'b' 'a'\n        l 'apple' 'banana'\n        l 'b' 'a'\n        g 'ab' 'a'\n        l 'a' 'a'\n";
+    assert_eq!(eval(code), "[true, true, false, true, false]");
+}
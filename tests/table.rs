@@ -0,0 +1,174 @@
+mod common;
+
+use common::{error_position, eval, eval_error};
+
+const POINT: &str = "
+var point
+    table
+        var x 1
+        var y 2
+";
+
+#[test]
+fn table_keys_and_values_of_the_entries() {
+    let code = format!(
+        "{POINT}return\n    list\n        table-keys point\n        table-values point\n        table-length point\n"
+    );
+    assert_eq!(eval(&code), "[[\"x\", \"y\"], [1, 2], 2]");
+}
+
+#[test]
+fn table_has_and_get_take_runtime_keys() {
+    let code = format!(
+        "{POINT}var key\n    add 'x' ''\nreturn\n    list\n        table-has point key\n        table-has point 'z'\n        table-get point key\n        table-get point 'z' 0\n"
+    );
+    assert_eq!(eval(&code), "[true, false, 1, 0]");
+
+    let error = eval_error(&format!("{POINT}table-get point 'z'\n"));
+    assert_eq!(error.message(), "Key 'z' is not found in the table.");
+    assert_eq!(error_position(&error), (5, 17));
+}
+
+#[test]
+fn table_set_and_remove_change_the_table() {
+    let code = format!(
+        "{POINT}var same\n    table-set point 'z' 3\nvar removed\n    table-remove point 'x'\nreturn\n    list same removed point\n"
+    );
+    assert_eq!(
+        eval(&code),
+        "[<Table {y: 2, z: 3}>, 1, <Table {y: 2, z: 3}>]"
+    );
+
+    let error = eval_error(&format!("{POINT}table-remove point 'z'\n"));
+    assert_eq!(error.message(), "Key 'z' is not found in the table.");
+}
+
+#[test]
+fn table_merge_lets_later_tables_override() {
+    let code = format!(
+        "{POINT}var merged\n    table-merge point\n        table\n            var y 5\n            var w 6\nreturn\n    list merged point\n"
+    );
+    assert_eq!(
+        eval(&code),
+        "[<Table {x: 1, y: 5, w: 6}>, <Table {x: 1, y: 2}>]"
+    );
+    assert_eq!(eval("return\n    table-merge\n"), "<Table {}>");
+}
+
+#[test]
+fn entries_keep_their_insertion_order() {
+    let code = "
+var letters
+    table
+        var c 1
+        var a 2
+        var b 3
+var keys
+    list
+for key value letters
+    list-push keys key
+return
+    list keys letters
+";
+    assert_eq!(
+        eval(code),
+        "[[\"c\", \"a\", \"b\"], <Table {c: 1, a: 2, b: 3}>]"
+    );
+}
+
+#[test]
+fn table_remove_keeps_the_order_of_the_rest() {
+    let code = "
+var letters
+    table
+        var c 1
+        var a 2
+        var b 3
+table-remove letters 'c'
+table-set letters 'c' 4
+table-set letters 'a' 5
+return
+    list
+        table-keys letters
+        table-values letters
+";
+    assert_eq!(eval(code), "[[\"a\", \"b\", \"c\"], [5, 3, 4]]");
+}
+
+#[test]
+fn equality_of_tables_depends_on_the_order() {
+    let code = "
+var ab
+    table
+        var a 1
+        var b 2
+var ba
+    table
+        var b 2
+        var a 1
+var ab-again
+    table
+        var a 1
+        var b 2
+return
+    list
+        eq ab ba
+        eq ab ab-again
+";
+    assert_eq!(eval(code), "[false, true]");
+}
+
+const PROTOTYPE: &str = "
+var base
+    table
+        var greeting 'hi'
+var child
+    table-extend base
+        var own 1
+";
+
+#[test]
+fn identifiers_fall_through_to_the_prototype() {
+    let code = format!(
+        "{PROTOTYPE}table-set base 'greeting' 'hello'\nreturn\n    list\n        child\n            return greeting\n        table-get child 'greeting' null\n        table-keys child\n"
+    );
+    assert_eq!(eval(&code), "[\"hello\", null, [\"own\"]]");
+}
+
+#[test]
+fn set_declares_identifiers_of_the_prototype_in_the_table() {
+    let code = format!("{PROTOTYPE}child\n    set greeting 'hey'\nreturn\n    list base child\n");
+    assert_eq!(
+        eval(&code),
+        "[<Table {greeting: \"hi\"}>, <Table {own: 1, greeting: \"hey\"}>]"
+    );
+}
+
+#[test]
+fn table_prototype_and_table_set_prototype() {
+    let code = format!(
+        "{PROTOTYPE}var before\n    table-prototype child\ntable-set-prototype child null\nreturn\n    list\n        eq before base\n        table-prototype child\n        table-prototype base\n"
+    );
+    assert_eq!(eval(&code), "[true, null, null]");
+}
+
+#[test]
+fn prototypes_cannot_make_a_cycle() {
+    let error = eval_error(&format!("{PROTOTYPE}table-set-prototype base child\n"));
+    assert_eq!(
+        error.message(),
+        "Prototype would make a cycle in the prototype chain."
+    );
+    assert_eq!(error_position(&error), (7, 25));
+
+    let error = eval_error(&format!("{PROTOTYPE}table-set-prototype base base\n"));
+    assert_eq!(
+        error.message(),
+        "Prototype would make a cycle in the prototype chain."
+    );
+
+    let code = format!(
+        "{PROTOTYPE}try\n    table-set-prototype base child\n    catch error\n        add 0 0\nreturn\n    table-prototype base\n"
+    );
+    assert_eq!(eval(&code), "null");
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::{error_position, eval, eval_error};
+
+#[test]
+fn interpolates_variables_into_strings() {
+    let code = "var name 'world'\nvar count 3\nreturn 'hello `name`, `count` times``'\n";
+    assert_eq!(eval(code), "hello world, 3 times``");
+}
+
+#[test]
+fn unterminated_interpolation_is_an_error() {
+    let error = eval_error("var text 'a `b'\n");
+    assert!(error.message().contains("Unterminated"));
+    assert_eq!(error_position(&error).0, 0);
+}
+
+#[test]
+fn standard_commands_are_not_shadowed() {
+    assert_eq!(eval("var add 5\nreturn\n    add 1 2\n"), "3");
+}
+
+#[test]
+fn loads_see_entries_added_at_runtime() {
+    let code = "
+var total 0
+for number
+    list 1 2 3
+    var doubled
+        mul number 2
+    set total
+        add total doubled
+return total
+";
+    assert_eq!(eval(code), "12");
+}
+
+#[test]
+fn loads_fall_through_the_prototype_chain() {
+    let code = "
+var base
+    table
+        var greeting 'hi'
+var derived
+    table-extend base
+        var name 'derived'
+return
+    derived
+        return '`greeting` `name`'
+";
+    assert_eq!(eval(code), "hi derived");
+}
+
+#[test]
+fn undefined_identifiers_are_marked() {
+    let error = eval_error("var x\n    add 1 missing\n");
+    assert_eq!(error_position(&error), (1, 10));
+}
+
+#[test]
+fn loads_see_variables_shadowing_their_slot() {
+    let code = "
+var x 'global'
+var make
+    closure
+        var get
+            closure
+                return x
+var frame
+    make
+var get
+    table-get frame 'get'
+var before
+    get
+table-set frame 'x' 'frame'
+return
+    list before
+        get
+";
+    assert_eq!(eval(code), "[\"global\", \"frame\"]");
+}
+
+#[test]
+fn loads_see_entries_moved_from_their_slot() {
+    let code = "
+var t
+    table
+        var a 1
+        var b 2
+var get-b
+    closure
+        return
+            t
+                return b
+var before
+    get-b
+table-remove t 'a'
+table-set t 'c' 3
+return
+    list before
+        get-b
+";
+    assert_eq!(eval(code), "[2, 2]");
+}
+
+#[test]
+fn set_changes_the_innermost_variable() {
+    let code = "
+var x 0
+var bump
+    closure
+        set x
+            add x 1
+var make
+    closure
+        var bump-own
+            closure
+                set x
+                    add x 10
+var frame
+    make
+var bump-own
+    table-get frame 'bump-own'
+bump
+bump-own
+bump
+table-set frame 'x' 0
+bump-own
+return
+    list x
+        table-get frame 'x'
+";
+    assert_eq!(eval(code), "[12, 10]");
+}